@@ -0,0 +1,169 @@
+// Advent of Code 2022
+// Opt-in counting of heap allocations
+
+//! With the `alloc-stats` feature, a counting global allocator is installed
+//! in every binary using this crate, and [`measure`] reports the heap
+//! allocations of each stage of a solution. Counters are kept per thread, so
+//! stages running concurrently on other threads don't affect each other.
+
+use std::fmt;
+
+/// Heap allocations made while running one stage of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Bytes requested by those allocations; a reallocation counts its new
+    /// size.
+    pub bytes: u64,
+    /// Most heap in use at any time during the stage, beyond what was in use
+    /// when it started.
+    pub peak: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} bytes, peak {} bytes",
+            self.allocations, self.bytes, self.peak
+        )
+    }
+}
+
+/// Runs `f` and returns its result together with the allocations it made on
+/// the calling thread, or `None` without the `alloc-stats` feature.
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let start = counting::Counters::get();
+    counting::reset_peak();
+    let value = f();
+    let end = counting::Counters::get();
+
+    let stats = AllocStats {
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak: (end.peak - start.current).max(0) as u64,
+    };
+    (value, Some(stats))
+}
+
+/// Runs `f` and returns its result together with the allocations it made on
+/// the calling thread, or `None` without the `alloc-stats` feature.
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) { (f(), None) }
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    thread_local! {
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        static BYTES: Cell<u64> = const { Cell::new(0) };
+        // Memory freed by this thread may have been allocated by another, so
+        // the heap in use by a single thread can drop below zero.
+        static CURRENT: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    /// Snapshot of the counters of the calling thread.
+    pub struct Counters {
+        pub allocations: u64,
+        pub bytes: u64,
+        pub current: i64,
+        pub peak: i64,
+    }
+
+    impl Counters {
+        pub fn get() -> Self {
+            Self {
+                allocations: ALLOCATIONS.get(),
+                bytes: BYTES.get(),
+                current: CURRENT.get(),
+                peak: PEAK.get(),
+            }
+        }
+    }
+
+    /// Restarts tracking the peak from the heap currently in use.
+    pub fn reset_peak() { PEAK.set(CURRENT.get()) }
+
+    /// Records an allocation of `size` bytes, and `freed` bytes released by
+    /// it in case of a reallocation.
+    fn allocated(size: usize, freed: usize) {
+        // The counters can't be used while the thread is being torn down,
+        // which is rare enough to just not count those allocations.
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        let _ = BYTES.try_with(|n| n.set(n.get() + size as u64));
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + size as i64 - freed as i64);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+        });
+    }
+
+    fn freed(size: usize) {
+        let _ = CURRENT.try_with(|current| current.set(current.get() - size as i64));
+    }
+
+    /// The system allocator, counting allocations on every thread.
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                allocated(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+}
+
+#[cfg(all(test, feature = "alloc-stats"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let (v, stats) = measure(|| {
+            let v = vec![0u8; 1000];
+            drop(vec![0u8; 5000]);
+            v
+        });
+        let stats = stats.unwrap();
+        assert_eq!(v.len(), 1000);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 6000);
+        assert_eq!(stats.peak, 6000);
+
+        let (_, stats) = measure(|| drop(v));
+        assert_eq!(stats.unwrap(), AllocStats::default());
+    }
+}
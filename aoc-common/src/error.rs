@@ -0,0 +1,134 @@
+// Advent of Code 2022
+// Parse errors pointing into the puzzle input
+
+use std::{error::Error, fmt};
+
+/// Error in the puzzle input, with the location and text of the offending
+/// line so it can be shown with the problem underlined.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+    /// Number of characters to underline, at least 1.
+    pub len: usize,
+    /// The offending line of the input, or its end if too long to keep.
+    pub text: String,
+    /// Column of the first character of `text`, 1 unless it is only the end
+    /// of the line.
+    pub text_column: usize,
+}
+
+impl ParseError {
+    /// Error at `span`, which must be a subslice of `text`, the input line
+    /// with number `line`. An empty span points just past its position, e.g.
+    /// at the end of the line for a missing field.
+    pub fn at(line: usize, text: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|&offset| offset + span.len() <= text.len())
+            .expect("span should be part of the line");
+
+        Self {
+            message: message.into(),
+            line,
+            column: text[..offset].chars().count() + 1,
+            len: span.chars().count().max(1),
+            text: text.to_string(),
+            text_column: 1,
+        }
+    }
+
+    /// Error at the last character of `excerpt`, the end of the input line
+    /// with number `line` up to `column`, for lines too long to keep whole.
+    pub fn in_excerpt(
+        line: usize,
+        column: usize,
+        excerpt: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        let len = excerpt.chars().count();
+        Self {
+            message: message.into(),
+            line,
+            column,
+            len: 1,
+            text: excerpt.to_string(),
+            text_column: (column + 1).saturating_sub(len).max(1),
+        }
+    }
+
+    /// Error about the whole input line `text` with number `line`.
+    pub fn line(line: usize, text: &str, message: impl Into<String>) -> Self {
+        Self::at(line, text, text, message)
+    }
+
+    /// Error at the end of `text`, the input line with number `line`.
+    pub fn end_of_line(line: usize, text: &str, message: impl Into<String>) -> Self {
+        Self::at(line, text, &text[text.len()..], message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{gutter}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        let ellipsis = if self.text_column > 1 { "…" } else { "" };
+        writeln!(f, "{} | {ellipsis}{}", self.line, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - self.text_column + ellipsis.chars().count()),
+            "^".repeat(self.len)
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Lines of `input` together with their line numbers, starting at 1.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let text = "A Q";
+        let e = ParseError::at(3, text, &text[2..], "invalid shape 'Q'");
+        assert_eq!((e.line, e.column, e.len), (3, 3, 1));
+
+        let e = ParseError::end_of_line(3, text, "missing field");
+        assert_eq!((e.line, e.column, e.len), (3, 4, 1));
+    }
+
+    #[test]
+    fn render() {
+        let text = "move 1 from x to 1";
+        let e = ParseError::at(12, text, &text[12..13], "invalid stack number");
+        assert_eq!(
+            e.to_string(),
+            "invalid stack number\n  --> line 12, column 13\n   |\n12 | move 1 \
+             from x to 1\n   |             ^"
+        );
+    }
+
+    #[test]
+    fn render_excerpt() {
+        let e = ParseError::in_excerpt(1, 5000, "abcX", "Invalid character 'X'");
+        assert_eq!(e.text_column, 4997);
+        assert_eq!(
+            e.to_string(),
+            "Invalid character 'X'\n --> line 1, column 5000\n  |\n1 | …abcX\n  |     ^"
+        );
+        let e = ParseError::in_excerpt(1, 3, "abX", "");
+        assert_eq!(e.to_string(), "\n --> line 1, column 3\n  |\n1 | abX\n  |   ^");
+    }
+}
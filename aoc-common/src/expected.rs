@@ -0,0 +1,180 @@
+// Advent of Code 2022
+// Expected answers recorded next to the puzzle inputs
+
+use crate::Puzzle;
+use anyhow::{anyhow, Context, Result};
+use std::{fmt::Write, fs, io, path::PathBuf};
+
+/// Name of the file in a day's `input` directory listing the expected
+/// answers.
+///
+/// Every line holds the name of an input file (without `.txt` extension)
+/// followed by the answers to both parts, separated by whitespace. Unknown
+/// answers are written as `-` and lines starting with `#` are comments.
+pub const FILE_NAME: &str = "answers.txt";
+
+/// Expected answers to both parts for one input file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Expected {
+    /// Expected answer to `part` (1 or 2), if known.
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        self.answers[usize::from(part) - 1].as_deref()
+    }
+}
+
+/// Parses the contents of an expected answers file.
+pub fn parse(s: &str) -> Result<Vec<Expected>> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [input, part1, part2] = fields[..] else {
+                return Err(anyhow!("line {}: expected 3 fields, found {}", i + 1, fields.len()));
+            };
+            let answer = |s: &str| (s != "-").then(|| s.to_string());
+
+            Ok(Expected {
+                input: input.to_string(),
+                answers: [answer(part1), answer(part2)],
+            })
+        })
+        .collect()
+}
+
+/// Path of the expected answers file of `puzzle`.
+pub fn path(puzzle: &Puzzle) -> PathBuf { PathBuf::from(puzzle.input_dir).join(FILE_NAME) }
+
+/// Loads the expected answers recorded for `puzzle`.
+pub fn load(puzzle: &Puzzle) -> Result<Vec<Expected>> {
+    let path = path(puzzle);
+    let s = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read expected answers {}", path.display()))?;
+    parse(&s).with_context(|| format!("invalid expected answers {}", path.display()))
+}
+
+/// Renders expected answers in the format of [`FILE_NAME`].
+pub fn render(expected: &[Expected]) -> String {
+    let mut s = "# input    part 1    part 2\n".to_string();
+    for e in expected {
+        let [part1, part2] = [1, 2].map(|part| e.answer(part).unwrap_or("-"));
+        s.push_str(&format!("{:<10} {:<9} {}\n", e.input, part1, part2));
+    }
+    s
+}
+
+/// Records `answer` as the expected answer to `part` for the input called
+/// `input` of `puzzle`.
+pub fn record(puzzle: &Puzzle, input: &str, part: u8, answer: &str) -> Result<()> {
+    let path = path(puzzle);
+    let mut expected = match fs::read_to_string(&path) {
+        Ok(s) => parse(&s)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e).context(format!("failed to read {}", path.display())),
+    };
+
+    let i = match expected.iter().position(|e| e.input == input) {
+        Some(i) => i,
+        None => {
+            expected.push(Expected {
+                input: input.to_string(),
+                answers: [None, None],
+            });
+            expected.len() - 1
+        }
+    };
+    expected[i].answers[usize::from(part) - 1] = Some(answer.to_string());
+
+    fs::write(&path, render(&expected))
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Solves every input listed in the expected answers of `puzzle` and fails
+/// with a diff of all answers that differ from the recorded ones. Puzzles
+/// supporting streaming input are checked both ways.
+pub fn check(puzzle: &Puzzle) -> Result<()> {
+    let mut diff = String::new();
+    let mut mismatches = 0;
+
+    for expected in load(puzzle)? {
+        let path = puzzle.input_path(&expected.input);
+        let mut runs = vec![(
+            "",
+            fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|input| (puzzle.solve)(&input, None)),
+        )];
+        if puzzle.stream.is_some() {
+            let streamed = fs::File::open(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|file| puzzle.solve_reader(&mut io::BufReader::new(file), None));
+            runs.push((" (streamed)", streamed));
+        }
+
+        for (how, actual) in &runs {
+            for part in 1..=2 {
+                let Some(want) = expected.answer(part) else {
+                    continue;
+                };
+                let got = match actual {
+                    Ok(report) => report.answers[usize::from(part) - 1].value.clone(),
+                    Err(e) => format!("error: {e:#}"),
+                };
+                if got != want {
+                    mismatches += 1;
+                    writeln!(diff, "  {} part {part}{how}:", expected.input)?;
+                    writeln!(diff, "    - {want}")?;
+                    writeln!(diff, "    + {got}")?;
+                }
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        return Err(anyhow!(
+            "{mismatches} answer(s) of {} differ from the expected ones:\n{diff}",
+            puzzle.name
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let expected = parse("# input part1 part2\nsample 24000 45000\n\ninput 67633 -\n").unwrap();
+        assert_eq!(
+            expected,
+            vec![
+                Expected {
+                    input: "sample".to_string(),
+                    answers: [Some("24000".to_string()), Some("45000".to_string())],
+                },
+                Expected {
+                    input: "input".to_string(),
+                    answers: [Some("67633".to_string()), None],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn render_roundtrip() {
+        let s = "# input    part 1    part 2\nsample     CMZ       MCD\ninput      -         1\n";
+        assert_eq!(render(&parse(s).unwrap()), s);
+    }
+
+    #[test]
+    fn parse_missing_field() {
+        assert!(parse("sample 24000\n").is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// Advent of Code 2022
+// Points, directions and boxes on the plane
+
+use anyhow::anyhow;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Point, or vector between points, with `x` to the right and `y` up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self { Self { x, y } }
+
+    /// Manhattan distance to `other`: steps needed when moving orthogonally.
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Chebyshev distance to `other`: steps needed when diagonal moves are
+    /// allowed too.
+    pub fn chebyshev(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Vector of the signs of both coordinates: a single step, diagonals
+    /// included, in the general direction of `self`.
+    pub fn signum(self) -> Self { Self::new(self.x.signum(), self.y.signum()) }
+
+    /// Point after a single step in `direction`.
+    pub fn step(self, direction: Direction) -> Self { self + direction.vector() }
+
+    /// Position of a knot at `self` after following the knot at `other`: a
+    /// step towards it, unless they touch (diagonally or overlapping).
+    pub fn catch_up(&self, other: &Self) -> Self {
+        match self.chebyshev(*other) > 1 {
+            true => *self + (*other - *self).signum(),
+            false => *self,
+        }
+    }
+
+    /// The four orthogonal neighbours: up, down, left and right.
+    pub fn neighbours4(self) -> [Self; 4] { Direction::ALL.map(|d| self.step(d)) }
+
+    /// All eight neighbours, diagonals included, counterclockwise from the
+    /// right.
+    pub fn neighbours8(self) -> [Self; 8] {
+        [(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)]
+            .map(|(x, y)| self + Self::new(x, y))
+    }
+
+    /// Vector rotated a quarter turn counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self { Self::new(-self.y, self.x) }
+
+    /// Vector rotated a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self { Self::new(self.y, -self.x) }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "({}, {})", self.x, self.y) }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self { Self::new(self.x + other.x, self.y + other.y) }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self { Self::new(self.x - other.x, self.y - other.y) }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) { *self = *self + other }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) { *self = *self - other }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self { Self::new(-self.x, -self.y) }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, n: isize) -> Self { Self::new(self.x * n, self.y * n) }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    /// Unit vector pointing in this direction.
+    pub const fn vector(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, 1),
+            Self::Down => Point::new(0, -1),
+            Self::Left => Point::new(-1, 0),
+            Self::Right => Point::new(1, 0),
+        }
+    }
+
+    /// Direction after a quarter turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    /// Direction after a quarter turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// The opposite direction.
+    pub const fn reverse(self) -> Self { self.turn_left().turn_left() }
+}
+
+/// Parses the letters `U`, `D`, `L` and `R`.
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Self::Up),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(anyhow!("Invalid direction '{}'", s)),
+        }
+    }
+}
+
+/// Smallest box containing a set of points, edges included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    /// Corner with the lowest coordinates.
+    pub min: Point,
+    /// Corner with the highest coordinates.
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// Bounding box of `points`, or `None` if there are none.
+    pub fn of(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bbox = Self {
+            min: first,
+            max: first,
+        };
+        points.for_each(|p| bbox.extend(p));
+        Some(bbox)
+    }
+
+    /// Grows the box to contain `p`.
+    pub fn extend(&mut self, p: Point) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    /// Number of columns of points in the box.
+    pub fn width(&self) -> usize { self.max.x.abs_diff(self.min.x) + 1 }
+
+    /// Number of rows of points in the box.
+    pub fn height(&self) -> usize { self.max.y.abs_diff(self.min.y) + 1 }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, -2));
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, -4));
+        assert_eq!(-a * 2, Point::new(-2, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point::new(1, -1));
+    }
+
+    #[test]
+    fn rotation() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().vector(), d.vector().rotate_left());
+            assert_eq!(d.turn_right().vector(), d.vector().rotate_right());
+            assert_eq!(d.reverse().vector(), -d.vector());
+        }
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        let p = Point::new(3, 3);
+        assert!(p.neighbours4().iter().all(|&n| p.manhattan(n) == 1));
+        assert!(p.neighbours8().iter().all(|&n| p.chebyshev(n) == 1));
+        assert_eq!(p.neighbours8().iter().filter(|&&n| p.manhattan(n) == 2).count(), 4);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let bbox = BoundingBox::of([Point::new(2, -1), Point::new(-3, 4), Point::ORIGIN]).unwrap();
+        assert_eq!((bbox.min, bbox.max), (Point::new(-3, -1), Point::new(2, 4)));
+        assert_eq!((bbox.width(), bbox.height()), (6, 6));
+        assert!(bbox.contains(Point::new(2, 4)));
+        assert!(!bbox.contains(Point::new(3, 0)));
+        assert_eq!(BoundingBox::of([]), None);
+    }
+}
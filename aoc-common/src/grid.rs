@@ -0,0 +1,265 @@
+// Advent of Code 2022
+// Rectangular grids of cells
+
+use crate::{error::numbered_lines, geometry::Direction, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Position of a cell as `(row, column)`, counting from the top left.
+pub type Pos = (usize, usize);
+
+/// Step between neighbouring cells as `(rows, columns)`.
+pub type Step = (isize, isize);
+
+/// Steps to the four orthogonal neighbours of a cell: up, down, left and
+/// right.
+pub const ORTHOGONAL: [Step; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Steps to all eight neighbours of a cell, diagonals included.
+pub const ALL_AROUND: [Step; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Step to the neighbouring cell in a direction, up being towards the first
+/// row.
+impl From<Direction> for Step {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `rows` by `cols` cells from `cells`, row by row.
+    ///
+    /// # Panics
+    ///
+    /// If there are not exactly `rows * cols` cells.
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), rows * cols, "grid of {rows}x{cols} cells");
+        Self { cells, rows, cols }
+    }
+
+    /// Creates a grid of `rows` by `cols` cells with the value of `f` for
+    /// every position.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Self { cells, rows, cols }
+    }
+
+    /// Parses a map with one character per cell, converted by `cell` or
+    /// rejected with the message it returns. All lines must be equally long.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut cols = None;
+        let mut rows = 0;
+
+        for (n, line) in numbered_lines(input) {
+            for (i, c) in line.char_indices() {
+                let span = &line[i..i + c.len_utf8()];
+                let value = cell(c).map_err(|message| ParseError::at(n, line, span, message))?;
+                cells.push(value);
+            }
+
+            let len = line.chars().count();
+            match cols {
+                None => cols = Some(len),
+                Some(cols) if cols != len => {
+                    let message = format!("Expected {cols} cells, found {len}");
+                    return Err(ParseError::line(n, line, message));
+                }
+                Some(_) => {}
+            }
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self { cells, rows, cols }),
+            _ => Err(ParseError::line(1, input.lines().next().unwrap_or(""), "Empty grid")),
+        }
+    }
+
+    pub fn rows(&self) -> usize { self.rows }
+
+    pub fn cols(&self) -> usize { self.cols }
+
+    /// Whether `pos` is inside the grid.
+    pub fn contains(&self, (row, col): Pos) -> bool { row < self.rows && col < self.cols }
+
+    /// Cell at `pos`, if it is inside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    /// Cell at `pos`, if it is inside the grid.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.0 * self.cols + pos.1]),
+            false => None,
+        }
+    }
+
+    /// Position one `step` away from `pos`, if it is inside the grid.
+    pub fn step(&self, (row, col): Pos, step: impl Into<Step>) -> Option<Pos> {
+        let (dr, dc) = step.into();
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Positions of all cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.rows).flat_map(|row| (0..self.cols).map(move |col| (row, col)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> { self.positions().zip(&self.cells) }
+
+    /// Cells of `row`, from left to right.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {row} outside of {}x{} grid", self.rows, self.cols);
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Cells of `col`, from top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.cols, "column {col} outside of {}x{} grid", self.rows, self.cols);
+        self.cells[col..].iter().step_by(self.cols.max(1))
+    }
+
+    /// Cells seen looking from `pos` in the direction of `step`, nearest
+    /// first, up to the edge of the grid. The cell at `pos` is not included.
+    pub fn ray(&self, pos: Pos, step: impl Into<Step>) -> impl Iterator<Item = (Pos, &T)> {
+        let step = step.into();
+        std::iter::successors(self.step(pos, step), move |&pos| self.step(pos, step))
+            .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Positions of the orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.into_iter().filter_map(move |step| self.step(pos, step))
+    }
+
+    /// Grid with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get(pos).unwrap_or_else(|| panic!("{pos:?} outside of {rows}x{cols} grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos:?} outside of {rows}x{cols} grid"))
+    }
+}
+
+/// Prints the grid as a map, one line per row, with the cells next to each
+/// other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Direction::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).ok_or(format!("Invalid digit '{c}'")))
+    }
+
+    #[test]
+    fn parse_and_print() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let e = digits("123\n4x6\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 2, "Invalid digit 'x'"));
+        assert_eq!(digits("123\n45\n").unwrap_err().line, 2);
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn lines_and_rays() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(grid.column(0).rev().copied().collect::<Vec<_>>(), [7, 4, 1]);
+
+        let ray = grid.ray((2, 1), Up).map(|(pos, &v)| (pos, v)).collect::<Vec<_>>();
+        assert_eq!(ray, [((1, 1), 5), ((0, 1), 2)]);
+        assert_eq!(grid.ray((0, 0), Left).count(), 0);
+    }
+
+    #[test]
+    fn neighbours_in_bounds() {
+        let grid = Grid::from_fn(2, 3, |(row, col)| row * 3 + col);
+        assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours((1, 1)).count(), 3);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.step((0, 2), Right), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 outside of 2x3 grid")]
+    fn column_out_of_bounds() {
+        let grid = Grid::from_fn(2, 3, |(row, col)| row * 3 + col);
+        let _ = grid.column(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 outside of 2x3 grid")]
+    fn row_out_of_bounds() {
+        let grid = Grid::from_fn(2, 3, |(row, col)| row * 3 + col);
+        let _ = grid.row(2);
+    }
+}
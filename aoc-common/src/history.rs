@@ -0,0 +1,400 @@
+// Advent of Code 2022
+// History of runs, to spot performance regressions
+
+//! Every run of a day through its binary or the runner appends one line of
+//! JSON per solved part to [`FILE_NAME`] at the root of the workspace, or to
+//! the file named by `AOC_HISTORY` (no file at all if it is empty). Runs are
+//! later compared between commits by their median time on the same input.
+
+use crate::{workspace_root, Puzzle, Report};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, BufRead, Read, Write},
+    path::{Path, PathBuf},
+    process::{self, Command},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+
+/// Name of the history file at the root of the workspace.
+pub const FILE_NAME: &str = "history.jsonl";
+
+/// Suffix of a commit given to [`compare`] selecting the runs made with
+/// uncommitted changes on top of it, e.g. `1a2b3c4d+dirty`.
+pub const DIRTY: &str = "+dirty";
+
+/// One part solved in one run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    /// Identifies the run, shared by all parts solved in it. Empty in older
+    /// histories.
+    #[serde(default)]
+    pub id: String,
+    /// Start of the run, in seconds since the Unix epoch.
+    pub time: u64,
+    /// Commit checked out, abbreviated, if built in a git repository.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    pub day: u8,
+    pub part: u8,
+    /// Name or path of the input as given, or `stdin`.
+    pub input: String,
+    /// SHA-256 of the input, in hex.
+    pub input_hash: String,
+    /// Whether the input was streamed, which reports all time as parsing.
+    pub stream: bool,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+/// SHA-256 of `input`, in hex.
+pub fn hash(input: &[u8]) -> String { format!("{:x}", Sha256::digest(input)) }
+
+/// Reader hashing everything read through it.
+pub struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: BufRead> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// SHA-256 of the whole input in hex, reading whatever is left of it.
+    pub fn finish(mut self) -> io::Result<String> {
+        io::copy(&mut self, &mut io::sink())?;
+        Ok(format!("{:x}", self.hasher.finalize()))
+    }
+}
+
+impl<R: BufRead> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> { self.inner.fill_buf() }
+
+    fn consume(&mut self, amt: usize) {
+        // The buffer is still filled, so this doesn't read anything.
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.hasher.update(&buf[..amt.min(buf.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+/// Path of the history file, or `None` if disabled by an empty
+/// `AOC_HISTORY`.
+pub fn path() -> Option<PathBuf> {
+    match env::var_os("AOC_HISTORY") {
+        Some(path) if path.is_empty() => None,
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(workspace_root().join(FILE_NAME)),
+    }
+}
+
+/// Output of git run with `args` in the workspace, if it succeeds.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).current_dir(workspace_root()).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Abbreviated commit named by `rev`, e.g. a branch, as recorded in the
+/// history. `None` if git doesn't know it.
+pub fn resolve_commit(rev: &str) -> Option<String> {
+    git(&["rev-parse", "--short=12", "--verify", "--quiet", &format!("{rev}^{{commit}}")])
+}
+
+/// Abbreviated commit checked out in the workspace, and whether tracked files
+/// have uncommitted changes. `None` outside of a git repository or without
+/// git.
+pub fn git_commit() -> Option<(String, bool)> {
+    let commit = resolve_commit("HEAD")?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+    Some((commit, dirty))
+}
+
+/// Appends the parts of `puzzle` solved in `report` to the history, for the
+/// input called `input` with hash `input_hash`.
+pub fn record(
+    puzzle: &Puzzle,
+    input: &str,
+    input_hash: &str,
+    stream: bool,
+    report: &Report,
+) -> Result<()> {
+    let Some(path) = path() else {
+        return Ok(());
+    };
+    // Unique across processes, and within one for runs in the same instant.
+    static RUNS: AtomicU64 = AtomicU64::new(0);
+    let start = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let id = format!(
+        "{:x}-{:x}-{}",
+        start.as_nanos(),
+        process::id(),
+        RUNS.fetch_add(1, Ordering::Relaxed)
+    );
+    let (commit, dirty) = match git_commit() {
+        Some((commit, dirty)) => (Some(commit), dirty),
+        None => (None, false),
+    };
+
+    let mut lines = String::new();
+    for answer in &report.answers {
+        let run = Run {
+            id: id.clone(),
+            time: start.as_secs(),
+            commit: commit.clone(),
+            dirty,
+            day: puzzle.day,
+            part: answer.part,
+            input: input.to_string(),
+            input_hash: input_hash.to_string(),
+            stream,
+            answer: answer.value.clone(),
+            parse_ns: report.parse.as_nanos() as u64,
+            solve_ns: answer.elapsed.as_nanos() as u64,
+        };
+        lines += &serde_json::to_string(&run)?;
+        lines.push('\n');
+    }
+
+    // A single write, so concurrent runs don't interleave their lines.
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .with_context(|| format!("failed to record run in {}", path.display()))
+}
+
+/// Loads all runs recorded in the history file at `path`.
+pub fn load(path: &Path) -> Result<Vec<Run>> {
+    let s = fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let location = || format!("{}: line {}", path.display(), i + 1);
+            serde_json::from_str(line).with_context(location)
+        })
+        .collect()
+}
+
+/// Median time of a stage in the runs of one commit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Median {
+    pub time: Duration,
+    /// Number of runs the median is taken over.
+    pub runs: usize,
+}
+
+impl Median {
+    fn of(mut times: Vec<u64>) -> Self {
+        times.sort_unstable();
+        Self {
+            time: Duration::from_nanos(times[times.len() / 2]),
+            runs: times.len(),
+        }
+    }
+}
+
+/// Timing of one stage of a day on one input, at a baseline and a current
+/// commit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    /// `parse`, or the number of the part.
+    pub stage: String,
+    /// Name of the input in the latest run.
+    pub input: String,
+    pub stream: bool,
+    pub baseline: Median,
+    pub current: Median,
+}
+
+impl Comparison {
+    /// Relative change of the median time, e.g. `0.25` for 25% slower.
+    pub fn change(&self) -> f64 {
+        self.current.time.as_secs_f64() / self.baseline.time.as_secs_f64().max(1e-9) - 1.0
+    }
+
+    /// Whether the stage got slower by more than `threshold`, relative to the
+    /// baseline.
+    pub fn regressed(&self, threshold: f64) -> bool { self.change() > threshold }
+}
+
+/// Whether `run` was made at the commit abbreviated or spelled out by
+/// `wanted`, with uncommitted changes only if `wanted` ends in [`DIRTY`].
+fn same_commit(run: &Run, wanted: &str) -> bool {
+    let (wanted, dirty) = match wanted.strip_suffix(DIRTY) {
+        Some(wanted) => (wanted, true),
+        None => (wanted, false),
+    };
+    run.dirty == dirty
+        && run
+            .commit
+            .as_deref()
+            .is_some_and(|commit| commit.starts_with(wanted) || wanted.starts_with(commit))
+}
+
+/// Compares the median times of every stage run on the same input at both
+/// the `baseline` and the `current` commit, given as prefixes of their
+/// hashes. Runs with uncommitted changes are only compared when the commit
+/// ends in [`DIRTY`], and then on their own.
+pub fn compare(runs: &[Run], baseline: &str, current: &str) -> Result<Vec<Comparison>> {
+    if [baseline, current].iter().any(|commit| commit.trim_end_matches(DIRTY).is_empty()) {
+        return Err(anyhow!("empty commit"));
+    }
+
+    // Times of each stage by day, input, streaming, stage and commit (baseline
+    // first). Parse times are recorded with every part of a run, so they are
+    // taken from its first part only. Streamed runs only have a parse time.
+    // Older histories lack run ids, so their runs are told apart by the rest.
+    type Key<'a> = (u8, &'a str, bool, String);
+    let mut times = BTreeMap::<Key, [Vec<u64>; 2]>::new();
+    let mut inputs = BTreeMap::<&str, &str>::new();
+    let mut previous = None;
+    for run in runs {
+        let side = match (same_commit(run, baseline), same_commit(run, current)) {
+            (true, _) => 0,
+            (_, true) => 1,
+            _ => continue,
+        };
+        inputs.insert(&run.input_hash, &run.input);
+
+        let key = |stage: String| (run.day, run.input_hash.as_str(), run.stream, stage);
+        let this = (&run.id, run.time, run.day, &run.input_hash, run.stream, &run.commit);
+        if previous != Some(this) {
+            times.entry(key("parse".to_string())).or_default()[side].push(run.parse_ns);
+            previous = Some(this);
+        }
+        if !run.stream {
+            times.entry(key(run.part.to_string())).or_default()[side].push(run.solve_ns);
+        }
+    }
+
+    Ok(times
+        .into_iter()
+        .filter(|(_, [baseline, current])| !baseline.is_empty() && !current.is_empty())
+        .map(|((day, hash, stream, stage), [baseline, current])| Comparison {
+            day,
+            stage,
+            input: inputs[hash].to_string(),
+            stream,
+            baseline: Median::of(baseline),
+            current: Median::of(current),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(commit: &str, time: u64, part: u8, parse_ns: u64, solve_ns: u64) -> Run {
+        Run {
+            id: format!("{commit}-{time}"),
+            time,
+            commit: Some(commit.to_string()),
+            dirty: false,
+            day: 9,
+            part,
+            input: "input".to_string(),
+            input_hash: hash(b"R 4\n"),
+            stream: false,
+            answer: "13".to_string(),
+            parse_ns,
+            solve_ns,
+        }
+    }
+
+    #[test]
+    fn hashing_reader() {
+        let input = "line 1\nline 2\nline 3\n";
+        let mut reader = HashingReader::new(input.as_bytes());
+        let mut first = String::new();
+        reader.read_line(&mut first).unwrap();
+        assert_eq!(first, "line 1\n");
+        assert_eq!(reader.finish().unwrap(), hash(input.as_bytes()));
+    }
+
+    #[test]
+    fn medians_per_stage() {
+        let runs = [
+            run("aaaa", 1, 1, 100, 1000),
+            run("aaaa", 1, 2, 100, 5000),
+            run("aaaa", 2, 1, 300, 3000),
+            run("aaaa", 3, 1, 200, 2000),
+            run("bbbb", 4, 1, 100, 2400),
+            run("cccc", 5, 1, 100, 9999),
+        ];
+
+        let comparisons = compare(&runs, "aaaa1234", "bb").unwrap();
+        let changes = comparisons
+            .iter()
+            .map(|c| (c.stage.as_str(), c.baseline, c.current))
+            .collect::<Vec<_>>();
+        let median = |ns, runs| Median {
+            time: Duration::from_nanos(ns),
+            runs,
+        };
+        assert_eq!(
+            changes,
+            [("1", median(2000, 3), median(2400, 1)), ("parse", median(200, 3), median(100, 1))]
+        );
+
+        assert!(comparisons[0].regressed(0.1));
+        assert!(!comparisons[0].regressed(0.25));
+        assert!(!comparisons[1].regressed(0.0));
+    }
+
+    #[test]
+    fn runs_in_the_same_second() {
+        let mut runs = [
+            run("aaaa", 1, 1, 100, 1000),
+            run("aaaa", 1, 1, 300, 1000),
+            run("bbbb", 2, 1, 100, 1000),
+        ];
+        runs[1].id = "second".to_string();
+
+        let comparisons = compare(&runs, "aaaa", "bbbb").unwrap();
+        assert_eq!(comparisons[1].stage, "parse");
+        assert_eq!(comparisons[1].baseline.runs, 2);
+    }
+
+    #[test]
+    fn dirty_runs_apart() {
+        let mut runs = [
+            run("aaaa", 1, 1, 100, 1000),
+            run("aaaa", 2, 1, 100, 5000),
+            run("bbbb", 3, 1, 100, 1000),
+        ];
+        runs[1].dirty = true;
+
+        let comparisons = compare(&runs, "aaaa", "aaaa+dirty").unwrap();
+        assert_eq!(comparisons[0].baseline.time, Duration::from_nanos(1000));
+        assert_eq!(comparisons[0].current.time, Duration::from_nanos(5000));
+        let comparisons = compare(&runs, "bbbb", "aaaa").unwrap();
+        assert_eq!(comparisons[0].current, Median { time: Duration::from_nanos(1000), runs: 1 });
+        assert!(compare(&runs, "+dirty", "aaaa").is_err());
+    }
+}
@@ -0,0 +1,460 @@
+// Advent of Code 2022
+// Shared scaffolding for the daily puzzle binaries
+
+pub mod alloc;
+pub mod error;
+pub mod expected;
+pub mod geometry;
+pub mod grid;
+pub mod history;
+pub mod output;
+pub mod parse;
+pub mod stream;
+pub mod trace;
+
+use alloc::AllocStats;
+use anyhow::{anyhow, Context, Result};
+pub use error::ParseError;
+use clap::{CommandFactory, FromArgMatches, Parser};
+use output::Format;
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+use tracing::{info_span, warn, Span};
+
+/// Root of the workspace, holding this crate, the runner and the days.
+pub fn workspace_root() -> &'static Path { Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap() }
+
+/// Reads the puzzle input of `puzzle`.
+///
+/// `input` is resolved with [`Puzzle::resolve_input`]. Without it the input
+/// is read from stdin when that is piped or redirected, and from the actual
+/// puzzle input otherwise.
+pub fn read_input(puzzle: &Puzzle, input: Option<&str>) -> Result<String> {
+    let stdin = io::stdin().lock();
+    let path = match input {
+        Some(input) => puzzle.resolve_input(input),
+        None if !stdin.is_terminal() => {
+            return io::read_to_string(stdin).context("failed to read puzzle input from stdin");
+        }
+        None => puzzle.input_path("input"),
+    };
+
+    fs::read_to_string(&path).with_context(|| format!("failed to read input {}", path.display()))
+}
+
+/// Opens the puzzle input of `puzzle` for reading it incrementally, selected
+/// like [`read_input`] does.
+pub fn open_input(puzzle: &Puzzle, input: Option<&str>) -> Result<Box<dyn BufRead>> {
+    let stdin = io::stdin().lock();
+    let path = match input {
+        Some(input) => puzzle.resolve_input(input),
+        None if !stdin.is_terminal() => return Ok(Box::new(stdin)),
+        None => puzzle.input_path("input"),
+    };
+
+    let file =
+        File::open(&path).with_context(|| format!("failed to open input {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Solves `part` of `puzzle` (or both) for the input selected like
+/// [`read_input`] does, in a single pass over it if `stream` is set, and
+/// records the run in the [`history`].
+pub fn solve_input(
+    puzzle: &Puzzle,
+    input: Option<&str>,
+    part: Option<u8>,
+    stream: bool,
+) -> Result<Report> {
+    let (report, hash) = match stream {
+        true => {
+            let mut reader = history::HashingReader::new(open_input(puzzle, input)?);
+            let report = puzzle.solve_reader(&mut reader, part)?;
+            (report, reader.finish().context("failed to read puzzle input")?)
+        }
+        false => {
+            let text = read_input(puzzle, input)?;
+            ((puzzle.solve)(&text, part)?, history::hash(text.as_bytes()))
+        }
+    };
+
+    let name = match input {
+        Some(input) => input,
+        None if !io::stdin().is_terminal() => "stdin",
+        None => "input",
+    };
+    if let Err(e) = history::record(puzzle, name, &hash, stream, &report) {
+        warn!("{e:#}");
+    }
+    Ok(report)
+}
+
+/// Runs `f` and returns its result together with the time it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let value = f();
+    (value, now.elapsed())
+}
+
+/// CPU time consumed so far by the calling thread, or zero where that is not
+/// available.
+pub fn thread_cpu_time() -> Duration {
+    #[cfg(unix)]
+    {
+        let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+        // SAFETY: `ts` is a valid timespec to write to.
+        if unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) } == 0 {
+            return Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32);
+        }
+    }
+
+    Duration::ZERO
+}
+
+/// Runs `f` and returns its result together with the wall-clock time and the
+/// CPU time of the calling thread it took.
+pub fn timed_cpu<T>(f: impl FnOnce() -> T) -> (T, Duration, Duration) {
+    let cpu = thread_cpu_time();
+    let (value, elapsed) = timed(f);
+    (value, elapsed, thread_cpu_time().saturating_sub(cpu))
+}
+
+/// Measurements of one stage of a solution: parsing or solving a part.
+struct Stage<T> {
+    value: T,
+    elapsed: Duration,
+    cpu: Duration,
+    alloc: Option<AllocStats>,
+}
+
+impl<T> Stage<T> {
+    /// Runs `f`, measuring its time and, with the `alloc-stats` feature, its
+    /// allocations.
+    fn run(f: impl FnOnce() -> T) -> Self {
+        let ((value, elapsed, cpu), alloc) = alloc::measure(|| timed_cpu(f));
+        Self {
+            value,
+            elapsed,
+            cpu,
+            alloc,
+        }
+    }
+}
+
+/// Formats `elapsed` and, if counted, the allocations of a stage.
+fn costs(elapsed: Duration, alloc: Option<AllocStats>) -> String {
+    match alloc {
+        Some(alloc) => format!("{elapsed:?}, {alloc}"),
+        None => format!("{elapsed:?}"),
+    }
+}
+
+/// Prints the time it took to parse the input, and the allocations made if
+/// they were counted.
+pub fn print_parsed(elapsed: Duration, alloc: Option<AllocStats>) {
+    println!("Parsed ({})", costs(elapsed, alloc));
+}
+
+/// Prints the answer to one part of a puzzle in the common format.
+pub fn print_answer(part: u8, answer: impl Display, elapsed: Duration, alloc: Option<AllocStats>) {
+    println!("Part {part}: {answer} ({})", costs(elapsed, alloc));
+}
+
+/// A puzzle solution, split into a parsing stage and the two parts.
+///
+/// The input is parsed once and both parts are solved from the same parsed
+/// data.
+pub trait Solution {
+    /// Parsed representation of the puzzle input, shared by the parts when
+    /// they are solved concurrently.
+    type Input: Sync;
+    /// Answer to the first part.
+    type Part1: Display;
+    /// Answer to the second part.
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// A solution that can also solve both parts in a single pass over its
+/// input, holding only a bounded part of it in memory.
+pub trait Streaming: Solution {
+    /// Solves both parts, reading the input incrementally from `reader`.
+    fn stream(reader: &mut dyn BufRead) -> Result<(Self::Part1, Self::Part2)>;
+}
+
+/// Answer to one part of a puzzle and the time it took to compute.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub part: u8,
+    pub value: String,
+    /// Wall-clock time.
+    pub elapsed: Duration,
+    /// CPU time of the solving thread.
+    pub cpu: Duration,
+    /// Allocations, with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+/// Answers to a puzzle together with the time spent parsing its input.
+#[derive(Clone, Debug)]
+pub struct Report {
+    /// Wall-clock time.
+    pub parse: Duration,
+    /// CPU time of the parsing thread.
+    pub parse_cpu: Duration,
+    /// Allocations while parsing, with the `alloc-stats` feature.
+    pub parse_alloc: Option<AllocStats>,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    pub fn print(&self) {
+        print_parsed(self.parse, self.parse_alloc);
+        for answer in &self.answers {
+            print_answer(answer.part, &answer.value, answer.elapsed, answer.alloc);
+        }
+    }
+}
+
+/// Parses `input` and solves `part` of `S`, or both parts if `part` is
+/// `None`, measuring every stage.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Report> {
+    solve_parts::<S>(input, part, false)
+}
+
+/// Parses `input` and solves `part` of `S` like [`solve`], with both parts
+/// solved concurrently on the rayon thread pool.
+pub fn solve_parallel<S: Solution>(input: &str, part: Option<u8>) -> Result<Report> {
+    solve_parts::<S>(input, part, true)
+}
+
+fn solve_parts<S: Solution>(input: &str, part: Option<u8>, parallel: bool) -> Result<Report> {
+    let parse = info_span!("parse").in_scope(|| Stage::run(|| S::parse(input)));
+    let parsed = parse.value.context("failed to parse puzzle input")?;
+
+    // Parts solved on another thread still belong to the caller's span.
+    let parent = Span::current();
+    let stage = |p: u8, f: fn(&S::Input) -> Result<String>| {
+        let span = info_span!(parent: &parent, "part", part = p);
+        part.is_none_or(|part| part == p).then(|| span.in_scope(|| Stage::run(|| f(&parsed))))
+    };
+    let part1 = || stage(1, |parsed| Ok(S::part1(parsed)?.to_string()));
+    let part2 = || stage(2, |parsed| Ok(S::part2(parsed)?.to_string()));
+    let stages = match parallel {
+        true => rayon::join(part1, part2),
+        false => (part1(), part2()),
+    };
+
+    let mut answers = Vec::with_capacity(2);
+    for (part, stage) in [(1, stages.0), (2, stages.1)] {
+        let Some(stage) = stage else {
+            continue;
+        };
+        answers.push(Answer {
+            part,
+            value: stage.value.with_context(|| format!("failed to solve part {part}"))?,
+            elapsed: stage.elapsed,
+            cpu: stage.cpu,
+            alloc: stage.alloc,
+        });
+    }
+
+    Ok(Report {
+        parse: parse.elapsed,
+        parse_cpu: parse.cpu,
+        parse_alloc: parse.alloc,
+        answers,
+    })
+}
+
+/// Solves `part` of `S`, or both parts if `part` is `None`, in a single pass
+/// over `reader`.
+///
+/// Reading and solving are interleaved, so all of the time and allocations
+/// are reported as parsing.
+pub fn solve_stream<S: Streaming>(reader: &mut dyn BufRead, part: Option<u8>) -> Result<Report> {
+    let stage = info_span!("stream").in_scope(|| Stage::run(|| S::stream(reader)));
+    let (part1, part2) = stage.value.context("failed to process puzzle input")?;
+
+    let answers = [(1, part1.to_string()), (2, part2.to_string())]
+        .into_iter()
+        .filter(|(p, _)| part.is_none_or(|part| part == *p))
+        .map(|(part, value)| Answer {
+            part,
+            value,
+            elapsed: Duration::ZERO,
+            cpu: Duration::ZERO,
+            alloc: stage.alloc.map(|_| AllocStats::default()),
+        })
+        .collect();
+
+    Ok(Report {
+        parse: stage.elapsed,
+        parse_cpu: stage.cpu,
+        parse_alloc: stage.alloc,
+        answers,
+    })
+}
+
+/// Type-erased [`solve_stream`] of one solution.
+pub type StreamFn = fn(&mut dyn BufRead, Option<u8>) -> Result<Report>;
+
+/// A day's solution with its type erased, so that days can be run from a
+/// single binary. Use [`puzzle!`] to declare one.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    /// Day of the calendar.
+    pub day: u8,
+    /// Name of the crate, e.g. `day-08-treetop-tree-house`.
+    pub name: &'static str,
+    /// Directory holding the sample and actual puzzle inputs.
+    pub input_dir: &'static str,
+    /// Solves the given part (or both) of the puzzle.
+    pub solve: fn(&str, Option<u8>) -> Result<Report>,
+    /// Like `solve`, with both parts solved concurrently.
+    pub solve_parallel: fn(&str, Option<u8>) -> Result<Report>,
+    /// Solves the given part (or both) in a single pass over the input, for
+    /// solutions implementing [`Streaming`].
+    pub stream: Option<StreamFn>,
+}
+
+impl Puzzle {
+    /// Creates the puzzle for `S`, taking the day from a crate name of the
+    /// form `day-NN-title`.
+    pub const fn new<S: Solution>(name: &'static str, input_dir: &'static str) -> Self {
+        let b = name.as_bytes();
+        let day = (b[4] - b'0') * 10 + (b[5] - b'0');
+
+        Self {
+            day,
+            name,
+            input_dir,
+            solve: solve::<S>,
+            solve_parallel: solve_parallel::<S>,
+            stream: None,
+        }
+    }
+
+    /// Creates the puzzle for `S` like [`Puzzle::new`], with support for
+    /// streaming input.
+    pub const fn streaming<S: Streaming>(name: &'static str, input_dir: &'static str) -> Self {
+        Self {
+            stream: Some(solve_stream::<S>),
+            ..Self::new::<S>(name, input_dir)
+        }
+    }
+
+    /// The puzzle solving both parts concurrently after parsing, e.g. to run
+    /// on a thread pool with other days.
+    pub fn parallel(&self) -> Self {
+        Self {
+            solve: self.solve_parallel,
+            ..*self
+        }
+    }
+
+    /// Solves `part` of the puzzle (or both) in a single pass over `reader`.
+    pub fn solve_reader(&self, reader: &mut dyn BufRead, part: Option<u8>) -> Result<Report> {
+        let stream = self
+            .stream
+            .ok_or_else(|| anyhow!("{} does not support streaming input", self.name))?;
+        stream(reader, part)
+    }
+
+    /// Path of the input file called `name` in the puzzle's `input`
+    /// directory. The `.txt` extension may be left out.
+    pub fn input_path(&self, name: &str) -> PathBuf {
+        let path = Path::new(self.input_dir).join(name);
+        match path.extension() {
+            Some(_) => path,
+            None => path.with_extension("txt"),
+        }
+    }
+
+    /// Resolves `input` to an input file: a path to an existing file is used
+    /// as is, anything else is taken as a name for [`Puzzle::input_path`].
+    pub fn resolve_input(&self, input: &str) -> PathBuf {
+        let path = Path::new(input);
+        if path.is_file() {
+            path.to_path_buf()
+        } else {
+            self.input_path(input)
+        }
+    }
+}
+
+/// Declares `PUZZLE`, the [`Puzzle`] of the calling day crate. Add
+/// `streaming` for solutions implementing [`Streaming`].
+#[macro_export]
+macro_rules! puzzle {
+    ($solution:ty) => {
+        pub const PUZZLE: $crate::Puzzle = $crate::Puzzle::new::<$solution>(
+            env!("CARGO_PKG_NAME"),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+        );
+    };
+    ($solution:ty, streaming) => {
+        pub const PUZZLE: $crate::Puzzle = $crate::Puzzle::streaming::<$solution>(
+            env!("CARGO_PKG_NAME"),
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input"),
+        );
+    };
+}
+
+/// Command line arguments shared by the binaries of all days.
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Output format of the answers and timings
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Path or name of the input file in the day's `input` directory, e.g.
+    /// `sample`. Defaults to stdin when piped, and to `input` otherwise
+    #[arg(short, long)]
+    pub input: Option<String>,
+    /// Read the input incrementally in bounded memory, for days supporting it
+    #[arg(long)]
+    pub stream: bool,
+    /// Print debug events and timings of the solution on stderr, or trace
+    /// events too when repeated. `RUST_LOG` takes precedence if set
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+}
+
+/// Entry point shared by all days.
+///
+/// Reads the input selected on the command line, solves both parts of the
+/// puzzle and reports any error on stderr, turning it into a failing exit
+/// code.
+pub fn run(puzzle: &Puzzle) -> ExitCode {
+    let matches = Args::command()
+        .name(puzzle.name)
+        .about(format!("Advent of Code 2022, day {}", puzzle.day))
+        .get_matches();
+    let args = match Args::from_arg_matches(&matches) {
+        Ok(args) => args,
+        Err(e) => e.exit(),
+    };
+
+    trace::init(args.verbose);
+    let _span = info_span!("day", day = puzzle.day).entered();
+
+    let report = solve_input(puzzle, args.input.as_deref(), None, args.stream);
+    let result = report.and_then(|report| output::print(args.format, &[(puzzle, &report)]));
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
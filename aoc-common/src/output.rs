@@ -0,0 +1,133 @@
+// Advent of Code 2022
+// Machine-readable output of answers and timings
+
+use crate::{Puzzle, Report};
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Output format of answers and timings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// A JSON array with one object per part
+    Json,
+    /// CSV with a header and one row per part
+    Csv,
+}
+
+/// One part of a puzzle in the machine-readable formats. The field names and
+/// their order form the schema of both JSON and CSV output; durations are in
+/// nanoseconds.
+#[derive(Clone, Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+const CSV_HEADER: &str = "day,part,answer,parse_ns,solve_ns";
+
+/// Flattens the reports into one record per solved part.
+pub fn records<'a>(reports: &[(&Puzzle, &'a Report)]) -> Vec<Record<'a>> {
+    reports
+        .iter()
+        .flat_map(|(puzzle, report)| {
+            report.answers.iter().map(|answer| Record {
+                day: puzzle.day,
+                part: answer.part,
+                answer: &answer.value,
+                parse_ns: report.parse.as_nanos() as u64,
+                solve_ns: answer.elapsed.as_nanos() as u64,
+            })
+        })
+        .collect()
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Writes `records` as a JSON array.
+pub fn write_json<W: Write>(mut w: W, records: &[Record]) -> Result<()> {
+    serde_json::to_writer_pretty(&mut w, records)?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Writes `records` as CSV, preceded by a header.
+pub fn write_csv<W: Write>(mut w: W, records: &[Record]) -> Result<()> {
+    writeln!(w, "{CSV_HEADER}")?;
+    for r in records {
+        writeln!(
+            w,
+            "{},{},{},{},{}",
+            r.day,
+            r.part,
+            csv_field(r.answer),
+            r.parse_ns,
+            r.solve_ns
+        )?;
+    }
+    Ok(())
+}
+
+/// Prints `reports` to stdout in `format`.
+pub fn print(format: Format, reports: &[(&Puzzle, &Report)]) -> Result<()> {
+    match format {
+        Format::Text => {
+            reports.iter().for_each(|(_, report)| report.print());
+            Ok(())
+        }
+        Format::Json => write_json(io::stdout().lock(), &records(reports)),
+        Format::Csv => write_csv(io::stdout().lock(), &records(reports)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(answer: &str) -> Record<'_> {
+        Record {
+            day: 5,
+            part: 1,
+            answer,
+            parse_ns: 1200,
+            solve_ns: 340,
+        }
+    }
+
+    #[test]
+    fn csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &[record("CMZ"), record("a,\"b\"")]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "day,part,answer,parse_ns,solve_ns\n5,1,CMZ,1200,340\n5,1,\"a,\"\"b\"\"\",1200,340\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &[record("CMZ")]).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                { "day": 5, "part": 1, "answer": "CMZ", "parse_ns": 1200, "solve_ns": 340 }
+            ])
+        );
+    }
+}
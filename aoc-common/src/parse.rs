@@ -0,0 +1,206 @@
+// Advent of Code 2022
+// Parser combinators for puzzle input formats
+
+//! Puzzle inputs are parsed line by line with [nom] parsers built from the
+//! combinators here, and [`line()`] turns their failures into [`ParseError`]s
+//! pointing at the offending part of the line.
+
+use crate::{error::numbered_lines, ParseError};
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, digit1, space1},
+    combinator::{all_consuming, opt, recognize},
+    error::{ErrorKind, FromExternalError},
+    sequence::{pair, preceded, separated_pair},
+    Parser,
+};
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+/// Result of a parser of (part of) one line.
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Error<'a>>;
+
+/// Failure of a line parser: the offending part of the line and what is wrong
+/// with it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// The word where parsing failed, or an empty span at the end of the line.
+    pub span: &'a str,
+    pub message: String,
+}
+
+impl<'a> Error<'a> {
+    /// Error about the word at the start of `input`.
+    pub fn new(input: &'a str, message: impl Into<String>) -> Self {
+        let len = input.find(char::is_whitespace).unwrap_or(input.len());
+        Self {
+            span: &input[..len],
+            message: message.into(),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let message = match kind {
+            _ if input.is_empty() => "Unexpected end of line",
+            ErrorKind::Eof => "Unexpected input",
+            ErrorKind::Space => "Expected a space",
+            _ => "Invalid input",
+        };
+        Self::new(input, message)
+    }
+
+    /// Keeps the innermost error, which is the most specific.
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self { other }
+}
+
+impl<'a, E: Display> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, _: ErrorKind, e: E) -> Self {
+        Self::new(input, e.to_string())
+    }
+}
+
+/// Parses all of `text`, the input line with number `n`, with `parser`.
+pub fn line<'a, T>(
+    n: usize,
+    text: &'a str,
+    parser: impl Parser<&'a str, T, Error<'a>>,
+) -> Result<T, ParseError> {
+    match all_consuming(parser)(text) {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(ParseError::at(n, text, e.span, e.message))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
+    }
+}
+
+/// Blocks of consecutive non-empty lines in `input`, separated by blank lines,
+/// with their line numbers.
+pub fn blocks(input: &str) -> impl Iterator<Item = Vec<(usize, &str)>> {
+    let mut lines = numbered_lines(input).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|(_, line)| line.is_empty()).is_some() {}
+        lines.peek()?;
+        Some(lines.by_ref().take_while(|(_, line)| !line.is_empty()).collect())
+    })
+}
+
+/// A decimal number, negative if `T` allows it.
+pub fn number<'a, T>(input: &'a str) -> IResult<'a, T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let digits: IResult<&str> = recognize(pair(opt(char('-')), digit1))(input);
+    let (rest, digits) = digits.map_err(|e| e.map(|_| Error::new(input, "Expected a number")))?;
+    match digits.parse() {
+        Ok(n) => Ok((rest, n)),
+        Err(e) => Err(nom::Err::Error(Error::new(input, format!("Invalid number: {e}")))),
+    }
+}
+
+/// A range of numbers like `2-4`, both ends included.
+pub fn range<'a, T>(input: &'a str) -> IResult<'a, RangeInclusive<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    separated_pair(number, literal("-"), number)
+        .map(|(start, end)| start..=end)
+        .parse(input)
+}
+
+/// A word: everything up to the next whitespace.
+pub fn word(input: &str) -> IResult<'_, &str> {
+    let word: IResult<&str> = take_till1(char::is_whitespace)(input);
+    word.map_err(|e| e.map(|_| Error::new(input, "Expected a word")))
+}
+
+/// The exact word `keyword`, as in keyword-delimited records like
+/// `move 1 from 2 to 3`.
+pub fn keyword<'a>(keyword: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |input| {
+        let (rest, word) = word(input)?;
+        match word == keyword {
+            true => Ok((rest, word)),
+            false => Err(nom::Err::Error(Error::new(input, format!("Expected '{keyword}'")))),
+        }
+    }
+}
+
+/// The value of `field` after the word `key` and a space, like the `2` of
+/// `from 2`.
+pub fn keyed<'a, T>(
+    key: &'static str,
+    field: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    preceded(pair(keyword(key), space1), field)
+}
+
+/// `parser` after some spaces, for the fields after the first one.
+pub fn spaced<'a, T>(
+    parser: impl Parser<&'a str, T, Error<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    preceded(space1, parser)
+}
+
+/// A value parsed from a word with its `FromStr` or `TryFrom<&str>`
+/// implementation, with the error message of the conversion.
+pub fn from_word<'a, T, E: Display>(
+    convert: impl Fn(&'a str) -> Result<T, E>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    move |input| {
+        let (rest, word) = word(input)?;
+        match convert(word) {
+            Ok(value) => Ok((rest, value)),
+            Err(e) => Err(nom::Err::Error(Error::new(input, e.to_string()))),
+        }
+    }
+}
+
+/// Exactly `text`, like a separator between fields.
+pub fn literal<'a>(text: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |input| {
+        let literal: IResult<&str> = tag(text)(input);
+        literal.map_err(|e| e.map(|_| Error::new(input, format!("Expected '{text}'"))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn numbers_and_ranges() {
+        assert_eq!(line(1, "-12", number::<i32>), Ok(-12));
+        assert_eq!(line(1, "2-4", range::<u8>), Ok(2..=4));
+
+        let e = line(1, "2-x", range::<u8>).unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (3, "Expected a number"));
+        let e = line(1, "300", number::<u8>).unwrap_err();
+        assert_eq!((e.column, e.len), (1, 3));
+        assert!(e.message.starts_with("Invalid number"));
+        let e = line(1, "2-4 ", range::<u8>).unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (4, "Unexpected input"));
+    }
+
+    #[test]
+    fn records() {
+        let mut record = pair(keyed("move", number::<u8>), spaced(keyed("to", word)));
+        assert_eq!(line(1, "move 3 to top", &mut record), Ok((3, "top")));
+
+        let e = line(2, "move 3 onto top", &mut record).unwrap_err();
+        assert_eq!((e.line, e.column, e.len, e.message.as_str()), (2, 8, 4, "Expected 'to'"));
+        let e = line(2, "move 3", &mut record).unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (7, "Unexpected end of line"));
+        let e = line(2, "move x to top", &mut record).unwrap_err();
+        assert_eq!((e.column, e.message.as_str()), (6, "Expected a number"));
+    }
+
+    #[test]
+    fn blank_line_separated_blocks() {
+        let blocks = blocks("\n1\n2\n\n\n3\n\n").collect::<Vec<_>>();
+        assert_eq!(blocks, [vec![(2, "1"), (3, "2")], vec![(6, "3")]]);
+    }
+}
@@ -0,0 +1,47 @@
+// Advent of Code 2022
+// Incremental reading of puzzle inputs
+
+use anyhow::{Context, Result};
+use std::io::BufRead;
+
+/// Calls `f` with every line of `reader` and its line number, starting at 1.
+///
+/// Lines are read into one reused buffer, so only the current line is held in
+/// memory. Line endings are stripped like [`str::lines`] does.
+pub fn for_each_line(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(usize, &str) -> Result<()>,
+) -> Result<()> {
+    let mut line = String::new();
+    for n in 1.. {
+        line.clear();
+        if reader.read_line(&mut line).context("failed to read puzzle input")? == 0 {
+            break;
+        }
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        f(n, text.strip_suffix('\r').unwrap_or(text))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lines_like_str_lines() {
+        let input = "1000\r\n2000\n\n3000";
+        let mut lines = Vec::new();
+        for_each_line(&mut input.as_bytes(), |n, line| {
+            lines.push((n, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+
+        let expected = crate::error::numbered_lines(input)
+            .map(|(n, line)| (n, line.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(lines, expected);
+    }
+}
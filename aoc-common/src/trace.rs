@@ -0,0 +1,35 @@
+// Advent of Code 2022
+// Diagnostics of the solutions on stderr
+
+//! Solutions are instrumented with [tracing]: a span for parsing and for each
+//! part, and debug and trace events inside the days. Nothing is printed unless
+//! asked for, and everything goes to stderr so answers on stdout stay clean.
+
+use std::io::{self, IsTerminal};
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+/// Filter used for `verbose` occurrences of `-v`: warnings only by default,
+/// debug events and the time spent in each span with `-v`, and everything
+/// with `-vv`.
+fn default_filter(verbose: u8) -> &'static str {
+    match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Prints the events and spans selected by `RUST_LOG` or, if it isn't set, by
+/// the number of `-v` flags on stderr.
+pub fn init(verbose: u8) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(default_filter(verbose)));
+
+    // Fails only if a subscriber is already set, which is then kept.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(io::stderr().is_terminal())
+        .with_writer(io::stderr)
+        .try_init();
+}
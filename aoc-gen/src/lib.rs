@@ -0,0 +1,206 @@
+// Advent of Code 2022
+// Random valid puzzle inputs
+
+pub mod reference;
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::io::{self, Write};
+
+/// Days for which inputs can be generated.
+pub const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 8, 9];
+
+/// Writes an input for `day` from `seed` to `out` as it is generated, so
+/// that inputs of any size take little memory. The same seed and size always
+/// give the same input. Fails with [`io::ErrorKind::Unsupported`] if the day
+/// has no generator.
+///
+/// `size` is the number of records in the input: elves, rounds, rucksacks,
+/// section assignment pairs, rearrangement steps, characters in the stream,
+/// rows and columns of the forest, or motions of the rope.
+pub fn write<W: Write>(out: &mut W, day: u8, seed: u64, size: usize) -> io::Result<()> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let rng = &mut rng;
+
+    match day {
+        1 => calorie_counting(rng, size, out),
+        2 => rock_paper_scissors(rng, size, out),
+        3 => rucksack_reorganization(rng, size, out),
+        4 => camp_cleanup(rng, size, out),
+        5 => supply_stacks(rng, size, out),
+        6 => tuning_trouble(rng, size, out),
+        8 => treetop_tree_house(rng, size, out),
+        9 => rope_bridge(rng, size, out),
+        _ => Err(io::Error::new(io::ErrorKind::Unsupported, format!("no generator for day {day}"))),
+    }
+}
+
+/// Generates an input for `day` like [`write()`] into a string, or `None` if
+/// the day has no generator.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut input = Vec::new();
+    write(&mut input, day, seed, size).ok()?;
+    Some(String::from_utf8(input).expect("generated inputs are ASCII"))
+}
+
+/// Day 1: blocks of calories carried by `size` elves (at least 3).
+pub fn calorie_counting<R: Rng, W: Write>(rng: &mut R, size: usize, out: &mut W) -> io::Result<()> {
+    for elf in 0..size.max(3) {
+        if elf > 0 {
+            writeln!(out)?;
+        }
+        for _ in 0..rng.gen_range(1..=15) {
+            writeln!(out, "{}", rng.gen_range(1000..=70000))?;
+        }
+    }
+    Ok(())
+}
+
+/// Day 2: `size` rounds of the strategy guide.
+pub fn rock_paper_scissors<R: Rng, W: Write>(
+    rng: &mut R,
+    size: usize,
+    out: &mut W,
+) -> io::Result<()> {
+    for _ in 0..size {
+        let opponent = *[b'A', b'B', b'C'].choose(rng).unwrap() as char;
+        let player = *[b'X', b'Y', b'Z'].choose(rng).unwrap() as char;
+        writeln!(out, "{opponent} {player}")?;
+    }
+    Ok(())
+}
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Day 3: `size` rucksacks, rounded up to whole groups of three elves.
+///
+/// Each rucksack has exactly one item type in both compartments, and each
+/// group has exactly one badge carried by all three elves.
+pub fn rucksack_reorganization<R: Rng, W: Write>(
+    rng: &mut R,
+    size: usize,
+    out: &mut W,
+) -> io::Result<()> {
+    for _ in 0..size.div_ceil(3) {
+        let mut items = ITEMS.to_vec();
+        items.shuffle(rng);
+        let (badge, rest) = items.split_first().unwrap();
+
+        // Every elf of the group draws from its own item types plus the
+        // badge, so the badge is the only type they all carry.
+        for own in rest.chunks(rest.len() / 3) {
+            let shared = *own.choose(rng).unwrap();
+            let others = own.iter().copied().filter(|&c| c != shared).collect::<Vec<_>>();
+            let (left, right) = others.split_at(rng.gen_range(0..=others.len()));
+
+            let len = rng.gen_range(2..=16);
+            let mut first = vec![shared, *badge];
+            first.extend((2..len).map(|_| {
+                *left.iter().chain([&shared, badge]).collect::<Vec<_>>().choose(rng).unwrap()
+            }));
+            let mut second = vec![shared];
+            second.extend((1..len).map(|_| *right.choose(rng).unwrap_or(&shared)));
+            first.shuffle(rng);
+            second.shuffle(rng);
+
+            out.write_all(&first)?;
+            out.write_all(&second)?;
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+/// Day 4: `size` pairs of section assignments.
+pub fn camp_cleanup<R: Rng, W: Write>(rng: &mut R, size: usize, out: &mut W) -> io::Result<()> {
+    let range = |rng: &mut R| {
+        let start = rng.gen_range(1..=99);
+        (start, rng.gen_range(start..=99))
+    };
+    for _ in 0..size {
+        let ((a, b), (c, d)) = (range(rng), range(rng));
+        writeln!(out, "{a}-{b},{c}-{d}")?;
+    }
+    Ok(())
+}
+
+/// Day 5: a drawing of nine stacks and `size` rearrangement steps.
+///
+/// No step moves more crates than its stack holds, and no stack is ever
+/// emptied, so there is always a crate on top of every stack. Steps may move
+/// crates back onto the stack they come from.
+pub fn supply_stacks<R: Rng, W: Write>(rng: &mut R, size: usize, out: &mut W) -> io::Result<()> {
+    const STACKS: usize = 9;
+
+    let mut heights = (0..STACKS).map(|_| rng.gen_range(1..=8)).collect::<Vec<usize>>();
+    // With more crates than stacks, some stack always holds at least two.
+    heights[0] = heights[0].max(2);
+
+    let top = *heights.iter().max().unwrap();
+    for level in (0..top).rev() {
+        let layer = heights
+            .iter()
+            .map(|&h| match h > level {
+                true => format!("[{}]", rng.gen_range(b'A'..=b'Z') as char),
+                false => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", layer.join(" "))?;
+    }
+    let numbers = (1..=STACKS).map(|i| format!(" {i} ")).collect::<Vec<_>>();
+    writeln!(out, "{}\n", numbers.join(" "))?;
+
+    for _ in 0..size {
+        let candidates = (0..STACKS).filter(|&i| heights[i] >= 2).collect::<Vec<_>>();
+        let from = *candidates.choose(rng).unwrap();
+        let to = rng.gen_range(0..STACKS);
+        let n = rng.gen_range(1..heights[from]);
+        heights[from] -= n;
+        heights[to] += n;
+        writeln!(out, "move {n} from {} to {}", from + 1, to + 1)?;
+    }
+    Ok(())
+}
+
+/// Day 6: a stream of `size` characters (at least 14), whose markers only
+/// show up after a stretch of repetitive characters.
+pub fn tuning_trouble<R: Rng, W: Write>(rng: &mut R, size: usize, out: &mut W) -> io::Result<()> {
+    let size = size.max(14);
+    let mut letters = (b'a'..=b'z').collect::<Vec<u8>>();
+    letters.shuffle(rng);
+
+    // Three letters can't form a start-of-packet marker, thirteen can't form
+    // a start-of-message marker. The stream ends in fourteen distinct ones.
+    let prefix = size - 14;
+    for i in 0..prefix {
+        let alphabet = if i < prefix / 2 { 3 } else { 13 };
+        out.write_all(&[*letters[..alphabet].choose(rng).unwrap()])?;
+    }
+    letters[..14].shuffle(rng);
+    out.write_all(&letters[..14])?;
+    writeln!(out)
+}
+
+/// Day 8: a forest of `size` by `size` trees (at least 1).
+pub fn treetop_tree_house<R: Rng, W: Write>(
+    rng: &mut R,
+    size: usize,
+    out: &mut W,
+) -> io::Result<()> {
+    let size = size.max(1);
+    let mut row = vec![b'\n'; size + 1];
+    for _ in 0..size {
+        row[..size].iter_mut().for_each(|tree| *tree = rng.gen_range(b'0'..=b'9'));
+        out.write_all(&row)?;
+    }
+    Ok(())
+}
+
+/// Day 9: `size` motions of the head of the rope.
+pub fn rope_bridge<R: Rng, W: Write>(rng: &mut R, size: usize, out: &mut W) -> io::Result<()> {
+    for _ in 0..size {
+        let direction = *['U', 'D', 'L', 'R'].choose(rng).unwrap();
+        writeln!(out, "{direction} {}", rng.gen_range(1..=20))?;
+    }
+    Ok(())
+}
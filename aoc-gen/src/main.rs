@@ -0,0 +1,36 @@
+// Advent of Code 2022
+// Generator of random valid puzzle inputs
+
+use clap::Parser;
+use std::{
+    io::{self, BufWriter, ErrorKind, Write},
+    process::ExitCode,
+};
+
+#[derive(Parser)]
+#[command(about = "Generates random valid Advent of Code 2022 puzzle inputs")]
+struct Args {
+    /// Day to generate an input for
+    day: u8,
+    /// Seed of the random generator; the same seed gives the same input
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+    /// Number of records in the input, e.g. elves, rounds or motions
+    #[arg(short = 'n', long, default_value_t = 1000)]
+    size: usize,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    match aoc_gen::write(&mut out, args.day, args.seed, args.size).and_then(|()| out.flush()) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Whatever reads the input may stop early, e.g. `head`.
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// Advent of Code 2022
+// Naive reference solutions
+
+//! Deliberately naive solutions, written straight from the puzzle texts to
+//! check the actual solutions against. They favour obviousness over speed,
+//! and panic on invalid input.
+
+use std::collections::HashSet;
+
+/// Answers to both parts of `day` for `input`, or `None` if the day has no
+/// reference solution.
+pub fn solve(day: u8, input: &str) -> Option<[String; 2]> {
+    let strings = |answers: [u64; 2]| answers.map(|a| a.to_string());
+    match day {
+        1 => Some(strings(calorie_counting(input))),
+        2 => Some(strings(rock_paper_scissors(input))),
+        3 => Some(strings(rucksack_reorganization(input))),
+        4 => Some(strings(camp_cleanup(input))),
+        5 => Some(supply_stacks(input)),
+        6 => Some(strings(tuning_trouble(input))),
+        8 => Some(strings(treetop_tree_house(input))),
+        9 => Some(strings(rope_bridge(input))),
+        _ => None,
+    }
+}
+
+pub fn calorie_counting(input: &str) -> [u64; 2] {
+    let mut totals = input
+        .split("\n\n")
+        .map(|elf| elf.lines().map(|line| line.parse::<u64>().unwrap()).sum())
+        .collect::<Vec<u64>>();
+    totals.sort();
+    totals.reverse();
+
+    [totals[0], totals[0] + totals[1] + totals[2]]
+}
+
+pub fn rock_paper_scissors(input: &str) -> [u64; 2] {
+    let mut scores = [0, 0];
+    for round in input.lines() {
+        scores[0] += match round {
+            "A X" => 1 + 3,
+            "A Y" => 2 + 6,
+            "A Z" => 3,
+            "B X" => 1,
+            "B Y" => 2 + 3,
+            "B Z" => 3 + 6,
+            "C X" => 1 + 6,
+            "C Y" => 2,
+            "C Z" => 3 + 3,
+            _ => panic!("invalid round {round}"),
+        };
+        scores[1] += match round {
+            "A X" => 3,
+            "A Y" => 1 + 3,
+            "A Z" => 2 + 6,
+            "B X" => 1,
+            "B Y" => 2 + 3,
+            "B Z" => 3 + 6,
+            "C X" => 2,
+            "C Y" => 3 + 3,
+            "C Z" => 1 + 6,
+            _ => panic!("invalid round {round}"),
+        };
+    }
+    scores
+}
+
+fn priority(item: char) -> u64 {
+    let items = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    items.find(item).unwrap() as u64 + 1
+}
+
+pub fn rucksack_reorganization(input: &str) -> [u64; 2] {
+    let rucksacks = input.lines().collect::<Vec<_>>();
+
+    let mut shared = 0;
+    for rucksack in &rucksacks {
+        let (first, second) = rucksack.split_at(rucksack.len() / 2);
+        let item = first.chars().find(|&c| second.contains(c)).unwrap();
+        shared += priority(item);
+    }
+
+    let mut badges = 0;
+    for group in rucksacks.chunks(3) {
+        let item = group[0]
+            .chars()
+            .find(|&c| group[1].contains(c) && group[2].contains(c))
+            .unwrap();
+        badges += priority(item);
+    }
+
+    [shared, badges]
+}
+
+pub fn camp_cleanup(input: &str) -> [u64; 2] {
+    let mut counts = [0, 0];
+    for pair in input.lines() {
+        let sections = pair
+            .split(',')
+            .map(|range| {
+                let (start, end) = range.split_once('-').unwrap();
+                let (start, end) = (start.parse::<u32>().unwrap(), end.parse::<u32>().unwrap());
+                (start..=end).collect::<HashSet<u32>>()
+            })
+            .collect::<Vec<_>>();
+        let (a, b) = (&sections[0], &sections[1]);
+
+        if a.is_subset(b) || b.is_subset(a) {
+            counts[0] += 1;
+        }
+        if !a.is_disjoint(b) {
+            counts[1] += 1;
+        }
+    }
+    counts
+}
+
+pub fn supply_stacks(input: &str) -> [String; 2] {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+    let mut rows = drawing.lines().rev();
+    let count = rows.next().unwrap().split_whitespace().count();
+
+    let mut stacks = vec![Vec::new(); count];
+    for row in rows {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(1 + 4 * i) {
+                Some(' ') | None => {}
+                Some(c) => stack.push(c),
+            }
+        }
+    }
+
+    let mut one_by_one = stacks.clone();
+    let mut all_at_once = stacks;
+    for line in moves.lines() {
+        let words = line.split(' ').collect::<Vec<_>>();
+        let n = words[1].parse::<usize>().unwrap();
+        let from = words[3].parse::<usize>().unwrap() - 1;
+        let to = words[5].parse::<usize>().unwrap() - 1;
+
+        for _ in 0..n {
+            let c = one_by_one[from].pop().unwrap();
+            one_by_one[to].push(c);
+        }
+
+        let mut lifted = Vec::new();
+        for _ in 0..n {
+            lifted.insert(0, all_at_once[from].pop().unwrap());
+        }
+        all_at_once[to].extend(lifted);
+    }
+
+    [one_by_one, all_at_once].map(|stacks| stacks.iter().map(|s| s.last().unwrap()).collect())
+}
+
+pub fn tuning_trouble(input: &str) -> [u64; 2] {
+    let stream = input.trim_end().chars().collect::<Vec<_>>();
+    [4, 14].map(|size| {
+        for end in size..=stream.len() {
+            let window = &stream[end - size..end];
+            let distinct = (0..size).all(|i| (0..i).all(|j| window[i] != window[j]));
+            if distinct {
+                return end as u64;
+            }
+        }
+        0
+    })
+}
+
+pub fn treetop_tree_house(input: &str) -> [u64; 2] {
+    let grid = input
+        .lines()
+        .map(|line| line.bytes().map(|b| b - b'0').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let (rows, cols) = (grid.len() as isize, grid[0].len() as isize);
+
+    let mut visible = 0;
+    let mut best = 0;
+    for row in 0..rows {
+        for col in 0..cols {
+            let height = grid[row as usize][col as usize];
+            let mut seen = false;
+            let mut score = 1;
+
+            for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (mut r, mut c) = (row + dr, col + dc);
+                let mut trees = 0;
+                let mut blocked = false;
+                while r >= 0 && r < rows && c >= 0 && c < cols {
+                    trees += 1;
+                    if grid[r as usize][c as usize] >= height {
+                        blocked = true;
+                        break;
+                    }
+                    r += dr;
+                    c += dc;
+                }
+                seen |= !blocked;
+                score *= trees;
+            }
+
+            if seen {
+                visible += 1;
+            }
+            best = best.max(score);
+        }
+    }
+    [visible, best]
+}
+
+pub fn rope_bridge(input: &str) -> [u64; 2] {
+    [2, 10].map(|knots| {
+        let mut rope = vec![(0i32, 0i32); knots];
+        let mut visited = HashSet::from([(0, 0)]);
+
+        for line in input.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+            for _ in 0..steps.parse::<u32>().unwrap() {
+                match direction {
+                    "U" => rope[0].1 += 1,
+                    "D" => rope[0].1 -= 1,
+                    "L" => rope[0].0 -= 1,
+                    "R" => rope[0].0 += 1,
+                    _ => panic!("invalid direction {direction}"),
+                }
+                for i in 1..knots {
+                    let (dx, dy) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                    if dx.abs() > 1 || dy.abs() > 1 {
+                        rope[i].0 += dx.signum();
+                        rope[i].1 += dy.signum();
+                    }
+                }
+                visited.insert(rope[knots - 1]);
+            }
+        }
+        visited.len() as u64
+    })
+}
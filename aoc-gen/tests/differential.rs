@@ -0,0 +1,100 @@
+use aoc_common::Puzzle;
+use proptest::prelude::*;
+
+/// Checks that `puzzle` gives the same answers as the reference solution,
+/// also when solving both parts concurrently and when streaming the input if
+/// it supports that.
+fn agrees(puzzle: &Puzzle, input: &str) -> Result<(), TestCaseError> {
+    let want = aoc_gen::reference::solve(puzzle.day, input).unwrap();
+    let mut reports = vec![(puzzle.solve)(input, None), (puzzle.solve_parallel)(input, None)];
+    if puzzle.stream.is_some() {
+        reports.push(puzzle.solve_reader(&mut input.as_bytes(), None));
+    }
+
+    for report in reports {
+        let report = report.map_err(|e| TestCaseError::fail(format!("{e:#}")))?;
+        let answers = report.answers.iter().map(|a| a.value.clone()).collect::<Vec<_>>();
+        prop_assert_eq!(answers, &want, "input:\n{}", input);
+    }
+    Ok(())
+}
+
+/// Checks `puzzle` against the reference solution on generated inputs.
+fn generated(puzzle: &Puzzle, seed: u64, size: usize) -> Result<(), TestCaseError> {
+    agrees(puzzle, &aoc_gen::generate(puzzle.day, seed, size).unwrap())
+}
+
+/// Forests that are not square, with few heights so that ties are common.
+fn forest() -> impl Strategy<Value = String> {
+    (1..15usize, 1..15usize).prop_flat_map(|(rows, cols)| {
+        prop::collection::vec(prop::collection::vec(0..4u8, cols), rows).prop_map(|grid| {
+            grid.iter()
+                .map(|row| row.iter().map(|h| (b'0' + h) as char).chain(['\n']).collect::<String>())
+                .collect()
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn calorie_counting(seed: u64, size in 1..200usize) {
+        generated(&day_01_calorie_counting::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn rock_paper_scissors(seed: u64, size in 1..200usize) {
+        generated(&day_02_rock_paper_scissors::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn rucksack_reorganization(seed: u64, size in 1..200usize) {
+        generated(&day_03_rucksack_reorganization::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn camp_cleanup(seed: u64, size in 1..200usize) {
+        generated(&day_04_camp_cleanup::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn camp_cleanup_small_sections(
+        pairs in prop::collection::vec((1..6u32, 1..6u32, 1..6u32, 1..6u32), 1..50)
+    ) {
+        // Few distinct sections, so that shared and touching bounds are common.
+        let input = pairs
+            .iter()
+            .map(|&(a, b, c, d)| format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d)))
+            .collect::<String>();
+        agrees(&day_04_camp_cleanup::PUZZLE, &input)?;
+    }
+
+    #[test]
+    fn supply_stacks(seed: u64, size in 1..200usize) {
+        generated(&day_05_supply_stacks::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn tuning_trouble(seed: u64, size in 14..500usize) {
+        generated(&day_06_tuning_trouble::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn tuning_trouble_any_stream(stream in "[a-z]{0,100}|[a-e]{0,100}|[a-p]{0,100}") {
+        agrees(&day_06_tuning_trouble::PUZZLE, &stream)?;
+    }
+
+    #[test]
+    fn treetop_tree_house(seed: u64, size in 1..40usize) {
+        generated(&day_08_treetop_tree_house::PUZZLE, seed, size)?;
+    }
+
+    #[test]
+    fn treetop_tree_house_any_shape(grid in forest()) {
+        agrees(&day_08_treetop_tree_house::PUZZLE, &grid)?;
+    }
+
+    #[test]
+    fn rope_bridge(seed: u64, size in 1..200usize) {
+        generated(&day_09_rope_bridge::PUZZLE, seed, size)?;
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::Puzzle;
+
+const PUZZLES: &[Puzzle] = &[
+    day_01_calorie_counting::PUZZLE,
+    day_02_rock_paper_scissors::PUZZLE,
+    day_03_rucksack_reorganization::PUZZLE,
+    day_04_camp_cleanup::PUZZLE,
+    day_05_supply_stacks::PUZZLE,
+    day_06_tuning_trouble::PUZZLE,
+    day_08_treetop_tree_house::PUZZLE,
+    day_09_rope_bridge::PUZZLE,
+];
+
+#[test]
+fn generated_inputs_solve() -> anyhow::Result<()> {
+    for puzzle in PUZZLES {
+        for (seed, size) in [(0, 1), (1, 10), (2, 100), (3, 1000)] {
+            let input = aoc_gen::generate(puzzle.day, seed, size).unwrap();
+            (puzzle.solve)(&input, None).map_err(|e| {
+                e.context(format!("{} with seed {seed} and size {size}", puzzle.name))
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn same_seed_same_input() {
+    for &day in aoc_gen::DAYS {
+        assert_eq!(aoc_gen::generate(day, 7, 50), aoc_gen::generate(day, 7, 50));
+        assert_ne!(aoc_gen::generate(day, 7, 50), aoc_gen::generate(day, 8, 50));
+    }
+    assert_eq!(aoc_gen::generate(7, 0, 50), None);
+}
+
+#[test]
+fn markers_after_prefix() {
+    // The first distinct window only shows up in the last 14 characters.
+    let input = aoc_gen::generate(6, 0, 1000).unwrap();
+    let report = (day_06_tuning_trouble::PUZZLE.solve)(&input, None).unwrap();
+    assert_eq!(report.answers[1].value, "1000");
+}
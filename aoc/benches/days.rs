@@ -0,0 +1,47 @@
+// Advent of Code 2022
+// Benchmarks of the parse and solve stages of every day
+
+use aoc_common::{Puzzle, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::{fs, hint::black_box};
+
+/// Benchmarks parsing and both parts of `S` separately on the actual input
+/// of `puzzle`.
+fn bench_day<S: Solution>(c: &mut Criterion, puzzle: &Puzzle) {
+    let path = puzzle.input_path("input");
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!("skipping {}: no input at {}", puzzle.name, path.display());
+        return;
+    };
+    let parsed = S::parse(&input).expect("input should parse");
+
+    let mut group = c.benchmark_group(puzzle.name);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+/// Benchmarks the listed solutions, each with the `PUZZLE` of its crate.
+macro_rules! bench_days {
+    ($c:expr, $($day:ident::$solution:ident),* $(,)?) => {
+        $(bench_day::<$day::$solution>($c, &$day::PUZZLE);)*
+    };
+}
+
+fn days(c: &mut Criterion) {
+    bench_days!(
+        c,
+        day_01_calorie_counting::CalorieCounting,
+        day_02_rock_paper_scissors::RockPaperScissors,
+        day_03_rucksack_reorganization::RucksackReorganization,
+        day_04_camp_cleanup::CampCleanup,
+        day_05_supply_stacks::SupplyStacks,
+        day_06_tuning_trouble::TuningTrouble,
+        day_08_treetop_tree_house::TreetopTreeHouse,
+        day_09_rope_bridge::RopeBridge,
+    );
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
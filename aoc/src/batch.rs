@@ -0,0 +1,218 @@
+// Advent of Code 2022
+// Solving a directory of inputs
+
+use crate::watch::{self, Outcome};
+use anyhow::{anyhow, Context, Result};
+use aoc_common::Puzzle;
+use console::style;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Extension of the file next to an input holding its expected answers.
+///
+/// It holds the answers to both parts, separated by whitespace, with `-` for
+/// an unknown answer.
+pub const ANSWERS_EXTENSION: &str = "answers";
+
+/// One input of a batch, with the answers expected for it if known.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Case {
+    /// File name of the input.
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+/// Parses the contents of an answers file.
+pub fn parse_answers(s: &str) -> Result<[Option<String>; 2]> {
+    let fields = s.split_whitespace().collect::<Vec<_>>();
+    let [part1, part2] = fields[..] else {
+        return Err(anyhow!("expected 2 answers, found {}", fields.len()));
+    };
+    let answer = |s: &str| (s != "-").then(|| s.to_string());
+    Ok([answer(part1), answer(part2)])
+}
+
+/// Inputs in `dir`, sorted by name, with the answers in their sibling
+/// answers files.
+pub fn cases(dir: &Path) -> Result<Vec<Case>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_none_or(|ext| ext != ANSWERS_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let answers = path.with_extension(ANSWERS_EXTENSION);
+            let expected = match answers.is_file() {
+                true => fs::read_to_string(&answers)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|s| parse_answers(&s))
+                    .with_context(|| format!("invalid answers {}", answers.display()))?,
+                false => [None, None],
+            };
+
+            Ok(Case {
+                name: path.file_name().unwrap().to_string_lossy().into_owned(),
+                path,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Solves both parts of every input in `dir` and prints a table of the
+/// answers, checked against the expected ones when known, followed by the
+/// errors and panics. Returns whether every input was solved without
+/// disagreeing with its expected answers.
+pub fn run(puzzle: &Puzzle, dir: &Path) -> Result<bool> {
+    let cases = cases(dir)?;
+    if cases.is_empty() {
+        return Err(anyhow!("no inputs in {}", dir.display()));
+    }
+
+    let outcomes = cases
+        .iter()
+        .map(|case| {
+            let input = fs::read_to_string(&case.path)
+                .map_err(|e| format!("failed to read {}: {e}", case.path.display()));
+            [1, 2].map(|part| match &input {
+                Ok(input) => watch::solve_text(puzzle, input, part),
+                Err(e) => Err(e.clone()),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    print!("{}", render(&cases, &outcomes));
+    Ok(outcomes.iter().zip(&cases).all(|(outcomes, case)| {
+        outcomes
+            .iter()
+            .zip(&case.expected)
+            .all(|(outcome, expected)| watch::cell(outcome, expected.as_deref()).1 != Some(false))
+    }))
+}
+
+/// Renders the outcomes as a table with one row per input, followed by the
+/// errors and a summary.
+pub fn render(cases: &[Case], outcomes: &[[Outcome; 2]]) -> String {
+    let cells = cases
+        .iter()
+        .zip(outcomes)
+        .map(|(case, outcomes)| {
+            [0, 1].map(|part| watch::cell(&outcomes[part], case.expected[part].as_deref()))
+        })
+        .collect::<Vec<_>>();
+    let name_width = cases.iter().map(|case| case.name.chars().count()).max().unwrap_or(0).max(5);
+    let width = cells
+        .iter()
+        .map(|cells| cells[0].0.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+
+    let mut s = format!("{:<name_width$}  {:<width$}  Part 2\n", "Input", "Part 1");
+    let (mut agree, mut disagree, mut failed) = (0, 0, 0);
+    for ((case, outcomes), cells) in cases.iter().zip(outcomes).zip(&cells) {
+        s += &format!("{:<name_width$}", case.name);
+        for (i, (text, verdict)) in cells.iter().enumerate() {
+            let padded = match i {
+                0 => format!("{text:<width$}"),
+                _ => text.clone(),
+            };
+            let styled = match verdict {
+                Some(true) => style(padded).green(),
+                Some(false) => style(padded).red(),
+                None => style(padded),
+            };
+            s += &format!("  {styled}");
+        }
+        s += "\n";
+
+        match cells.iter().map(|(_, verdict)| *verdict).collect::<Vec<_>>() {
+            _ if outcomes.iter().any(Result::is_err) => failed += 1,
+            verdicts if verdicts.contains(&Some(false)) => disagree += 1,
+            _ => agree += 1,
+        }
+    }
+
+    for (case, outcomes) in cases.iter().zip(outcomes) {
+        for (part, outcome) in outcomes.iter().enumerate() {
+            if let Err(e) = outcome {
+                s += &format!("\n{}, part {}: {e}\n", case.name, part + 1);
+            }
+        }
+    }
+    s += &format!(
+        "\n{} input(s): {agree} agreeing or unchecked, {disagree} disagreeing, {failed} failed\n",
+        cases.len()
+    );
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn answers_files() {
+        let answers = parse_answers("13\n36\n").unwrap();
+        assert_eq!(answers, [Some("13".to_string()), Some("36".to_string())]);
+        assert_eq!(parse_answers("- 36").unwrap(), [None, Some("36".to_string())]);
+        assert!(parse_answers("13").is_err());
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let puzzle = Puzzle {
+            solve: |_, _| panic!("index out of bounds"),
+            ..day_09_rope_bridge::PUZZLE
+        };
+        let outcome = watch::solve_text(&puzzle, "R 4\n", 1);
+        assert_eq!(outcome, Err("panicked: index out of bounds".to_string()));
+    }
+
+    #[test]
+    fn solve_directory() {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let files = [
+            ("alice.txt", "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n"),
+            ("alice.answers", "13 1\n"),
+            ("bob.txt", "R 4\n"),
+            ("bob.answers", "5 -\n"),
+            ("carol.txt", "R x\n"),
+        ];
+        for (name, contents) in files {
+            fs::write(dir.join(name), contents).unwrap();
+        }
+
+        let cases = cases(&dir).unwrap();
+        let names = cases.iter().map(|case| case.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["alice.txt", "bob.txt", "carol.txt"]);
+        assert_eq!(cases[1].expected, [Some("5".to_string()), None]);
+
+        assert!(!run(&day_09_rope_bridge::PUZZLE, &dir).unwrap());
+        let outcomes = cases
+            .iter()
+            .map(|case| {
+                let input = fs::read_to_string(&case.path).unwrap();
+                [1, 2].map(|part| watch::solve_text(&day_09_rope_bridge::PUZZLE, &input, part))
+            })
+            .collect::<Vec<_>>();
+        let table = console::strip_ansi_codes(&render(&cases, &outcomes)).into_owned();
+        assert!(table.contains("alice.txt  13 ✓              1 ✓\n"), "{table}");
+        assert!(table.contains("bob.txt    4 ✗ (expected 5)  1\n"), "{table}");
+        assert!(table.contains("carol.txt  error"), "{table}");
+        assert!(table.ends_with("3 input(s): 1 agreeing or unchecked, 1 disagreeing, 1 failed\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+// Advent of Code 2022
+// Client for the Advent of Code website
+
+use crate::submissions::Verdict;
+use anyhow::{anyhow, Context, Result};
+use std::{fs, path::Path};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2022;
+const USER_AGENT: &str = "github.com/ArjenL/AoC2022";
+
+/// Client for the puzzle website at `base_url`, authenticated with the
+/// session cookie of a logged in user.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    fn cookie(&self) -> Result<String> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| anyhow!("no session token given (set AOC_SESSION or --session)"))?;
+        Ok(format!("session={session}"))
+    }
+
+    fn day_url(&self, day: u8) -> String { format!("{}/{YEAR}/day/{day}", self.base_url) }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie()?)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::StatusCode(400) => {
+                    anyhow!("session token rejected by {url}")
+                }
+                ureq::Error::StatusCode(404) => anyhow!("day {day} is not unlocked yet"),
+                e => anyhow!("failed to download {url}: {e}"),
+            })?;
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    /// Submits `answer` to `part` of `day` and returns the verdict.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie()?)
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|e| anyhow!("failed to submit to {url}: {e}"))?;
+
+        Verdict::from_html(&response.body_mut().read_to_string()?)
+    }
+
+    /// Downloads the puzzle input of `day` to `path`, unless that file
+    /// already exists. Returns whether the input was downloaded.
+    pub fn fetch_input(&self, day: u8, path: &Path) -> Result<bool> {
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let input = self.input(day)?;
+        // Write through a temporary file, so that an interrupted download
+        // never leaves a truncated input in the cache.
+        let partial = path.with_extension("part");
+        fs::write(&partial, input)
+            .and_then(|()| fs::rename(&partial, path))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scaffold;
+    use std::{
+        env,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves one canned HTTP response per entry of `responses` on a local
+    /// port. Returns the base URL of the server and a handle yielding the
+    /// received requests once all responses have been sent.
+    fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = v.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    /// A fresh, empty directory for the test called `name`.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn fetch_input_once() {
+        let (base_url, server) = serve(vec![(200, "1000\n2000\n".to_string())]);
+        let client = Client::new(&base_url, Some("secret".to_string()));
+        let path = temp_dir("fetch").join("input.txt");
+
+        assert!(client.fetch_input(1, &path).unwrap());
+        assert!(!client.fetch_input(1, &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn fetch_input_of_new_day() {
+        let (base_url, server) = serve(vec![(200, "$ cd /\n".to_string())]);
+        let client = Client::new(&base_url, Some("secret".to_string()));
+        let root = temp_dir("fetch-new");
+        let names = scaffold::Names::new(7, "no-space-left-on-device").unwrap();
+        let path = scaffold::new_day(&root, 7, &names).unwrap().join("input/input.txt");
+
+        assert!(client.fetch_input(7, &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "$ cd /\n");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn fetch_input_not_unlocked() {
+        let (base_url, server) = serve(vec![(404, "Not found".to_string())]);
+        let client = Client::new(&base_url, Some("secret".to_string()));
+        let path = temp_dir("fetch-404").join("input.txt");
+
+        assert!(client.fetch_input(25, &path).is_err());
+        assert!(!path.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submit_answer() {
+        let page = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let (base_url, server) = serve(vec![(200, page.to_string())]);
+        let client = Client::new(&base_url, Some("secret".to_string()));
+
+        assert_eq!(client.submit(4, 2, "794").unwrap(), Verdict::TooLow);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/4/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=794"));
+    }
+
+    #[test]
+    fn fetch_input_without_session() {
+        let client = Client::new("http://127.0.0.1:1", None);
+        let path = temp_dir("fetch-session").join("input.txt");
+
+        assert!(client.fetch_input(1, &path).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+// Advent of Code 2022
+// Registry of all solved days
+
+use aoc_common::Puzzle;
+
+pub const PUZZLES: &[Puzzle] = &[
+    day_01_calorie_counting::PUZZLE,
+    day_02_rock_paper_scissors::PUZZLE,
+    day_03_rucksack_reorganization::PUZZLE,
+    day_04_camp_cleanup::PUZZLE,
+    day_05_supply_stacks::PUZZLE,
+    day_06_tuning_trouble::PUZZLE,
+    day_08_treetop_tree_house::PUZZLE,
+    day_09_rope_bridge::PUZZLE,
+];
+
+/// Looks up the puzzle for `day`.
+pub fn find(day: u8) -> Option<&'static Puzzle> { PUZZLES.iter().find(|p| p.day == day) }
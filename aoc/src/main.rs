@@ -0,0 +1,591 @@
+// Advent of Code 2022
+// Runner for all days
+
+mod batch;
+mod client;
+mod days;
+mod readme;
+mod scaffold;
+mod submissions;
+mod watch;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{expected, history, output::Format, Puzzle, Report};
+use clap::{Args, Parser, Subcommand};
+use client::Client;
+use rayon::prelude::*;
+use std::{iter, path::PathBuf, process::ExitCode, str::FromStr, thread, time::Duration};
+use tracing::info_span;
+use submissions::{Submission, Verdict};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print debug events and timings of the solutions on stderr, or trace
+    /// events too when repeated. `RUST_LOG` takes precedence if set
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or all of them
+    Run {
+        /// Day to solve, or `all`
+        day: Days,
+        /// Only solve this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Path or name of the input file in the day's `input` directory
+        #[arg(short, long, default_value = "input")]
+        input: String,
+        /// Output format of the answers and timings
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+        /// Read the input incrementally in bounded memory, for days supporting it
+        #[arg(long)]
+        stream: bool,
+        /// Solve the days, and both parts of each after parsing, concurrently
+        /// on a thread pool and print a summary with wall-clock and CPU time
+        #[arg(long)]
+        parallel: bool,
+    },
+    /// Download the puzzle input of one day, or all of them, unless cached
+    Fetch {
+        /// Day to download, or `all`
+        day: Days,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Submit the answer to one part of a day's actual input
+    Submit {
+        /// Day to submit an answer for
+        day: u8,
+        /// Part to submit an answer for
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit, solved from the actual input if left out
+        answer: Option<String>,
+        /// Wait and retry when the previous answer was submitted too recently
+        #[arg(short, long)]
+        wait: bool,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Solve the sample and actual inputs of a day side by side, again
+    /// whenever they change, and rebuild when its sources change
+    Watch {
+        /// Day to watch
+        day: u8,
+    },
+    /// Solve every input file in a directory, checking the answers against
+    /// sibling `.answers` files
+    Batch {
+        /// Day of the inputs
+        day: u8,
+        /// Directory of input files
+        dir: PathBuf,
+    },
+    /// Compare the median times recorded in the run history at two commits,
+    /// flagging stages that got slower
+    Compare {
+        /// Commit, branch or tag to compare against. A `+dirty` suffix selects
+        /// the runs made with uncommitted changes on top of it
+        #[arg(short, long)]
+        baseline: String,
+        /// Commit, branch or tag to compare, by default the working tree
+        /// (with `+dirty` if it has uncommitted changes)
+        #[arg(short, long)]
+        current: Option<String>,
+        /// Slowdown of the median time flagged as a regression, in percent
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Solve every day on its actual input and regenerate the results table
+    /// in README.md
+    Readme,
+    /// Create the crate of a new day with a solution skeleton
+    New {
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Title of the puzzle, e.g. `no-space-left-on-device`
+        title: String,
+    },
+}
+
+/// Connection to the Advent of Code website.
+#[derive(Args)]
+struct Server {
+    /// Session cookie of a logged in user
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Base URL of the website
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl Server {
+    fn client(self) -> Client { Client::new(&self.base_url, self.session) }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Days {
+    All,
+    One(u8),
+}
+
+impl FromStr for Days {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Days::All),
+            _ => {
+                let day = s.parse::<u8>().map_err(|_| anyhow!("Invalid day '{}'", s))?;
+                days::find(day).ok_or_else(|| anyhow!("Day {} is not solved", day))?;
+                Ok(Days::One(day))
+            }
+        }
+    }
+}
+
+impl Days {
+    fn puzzles(self) -> Vec<&'static Puzzle> {
+        match self {
+            Days::All => days::PUZZLES.iter().collect(),
+            Days::One(day) => days::find(day).into_iter().collect(),
+        }
+    }
+}
+
+/// Reads the input `input` of `puzzle` and solves it, in a single pass over
+/// the input if `stream` is set.
+fn solve(puzzle: &Puzzle, input: &str, part: Option<u8>, stream: bool) -> Result<Report> {
+    let _span = info_span!("day", day = puzzle.day).entered();
+    aoc_common::solve_input(puzzle, Some(input), part, stream)
+}
+
+fn print_table(results: &[(&Puzzle, Result<Report>)]) {
+    println!("{:>3}  {:>4}  {:<20}  {:>12}  {:>12}", "Day", "Part", "Answer", "Parse", "Solve");
+
+    let mut total = Duration::ZERO;
+    for (puzzle, result) in results {
+        match result {
+            Ok(report) => {
+                total += report.parse;
+                for (i, answer) in report.answers.iter().enumerate() {
+                    total += answer.elapsed;
+                    let parse = if i == 0 { format!("{:?}", report.parse) } else { String::new() };
+                    println!(
+                        "{:>3}  {:>4}  {:<20}  {:>12}  {:>12}",
+                        puzzle.day,
+                        answer.part,
+                        answer.value,
+                        parse,
+                        format!("{:?}", answer.elapsed)
+                    );
+                }
+            }
+            Err(e) => println!("{:>3}  {:>4}  error: {e:#}", puzzle.day, "-"),
+        }
+    }
+
+    println!("{:>59}", format!("total: {total:?}"));
+}
+
+/// Prints the wall-clock and CPU time of every stage, their totals and the
+/// `elapsed` time of the whole run.
+fn print_summary(results: &[(&Puzzle, Result<Report>)], elapsed: Duration) {
+    println!("{:>3}  {:>5}  {:<20}  {:>12}  {:>12}", "Day", "Part", "Answer", "Wall", "CPU");
+
+    let (mut wall, mut cpu) = (Duration::ZERO, Duration::ZERO);
+    for (puzzle, result) in results {
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => {
+                println!("{:>3}  {:>5}  error: {e:#}", puzzle.day, "-");
+                continue;
+            }
+        };
+
+        let parse = ("parse".to_string(), "", report.parse, report.parse_cpu);
+        let parts = report
+            .answers
+            .iter()
+            .map(|a| (a.part.to_string(), a.value.as_str(), a.elapsed, a.cpu));
+        for (stage, answer, stage_wall, stage_cpu) in iter::once(parse).chain(parts) {
+            wall += stage_wall;
+            cpu += stage_cpu;
+            println!(
+                "{:>3}  {:>5}  {:<20}  {:>12}  {:>12}",
+                puzzle.day,
+                stage,
+                answer,
+                format!("{stage_wall:?}"),
+                format!("{stage_cpu:?}")
+            );
+        }
+    }
+
+    println!("{:>32}  {:>12}  {:>12}", "total:", format!("{wall:?}"), format!("{cpu:?}"));
+    let threads = format!("elapsed on {} thread(s):", rayon::current_num_threads());
+    println!("{threads:>32}  {:>12}", format!("{elapsed:?}"));
+}
+
+/// Prints the allocations of every stage, if they were counted.
+fn print_allocations(results: &[(&Puzzle, Result<Report>)]) {
+    let reports = results
+        .iter()
+        .filter_map(|(puzzle, result)| Some((puzzle, result.as_ref().ok()?)))
+        .filter(|(_, report)| report.parse_alloc.is_some())
+        .collect::<Vec<_>>();
+    if reports.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("{:>3}  {:>5}  {:>12}  {:>14}  {:>14}", "Day", "Part", "Allocs", "Bytes", "Peak");
+    for (puzzle, report) in reports {
+        let parse = ("parse".to_string(), report.parse_alloc);
+        let parts = report.answers.iter().map(|a| (a.part.to_string(), a.alloc));
+        for (stage, alloc) in iter::once(parse).chain(parts) {
+            let alloc = alloc.unwrap_or_default();
+            println!(
+                "{:>3}  {:>5}  {:>12}  {:>14}  {:>14}",
+                puzzle.day, stage, alloc.allocations, alloc.bytes, alloc.peak
+            );
+        }
+    }
+}
+
+/// Prints how the median time of every stage changed from the `baseline` to
+/// the `current` commit (the one checked out by default), and returns
+/// whether none got slower by more than `threshold` percent.
+fn compare(baseline: &str, current: Option<&str>, threshold: f64) -> Result<bool> {
+    let path = history::path().ok_or_else(|| anyhow!("the run history is disabled"))?;
+    let resolve = |rev: &str| {
+        let (rev, dirty) = match rev.strip_suffix(history::DIRTY) {
+            Some(rev) => (rev, history::DIRTY),
+            None => (rev, ""),
+        };
+        let commit = history::resolve_commit(rev).unwrap_or_else(|| rev.to_string());
+        format!("{commit}{dirty}")
+    };
+    let baseline = resolve(baseline);
+    let current = match current {
+        Some(current) => resolve(current),
+        None => match history::git_commit().ok_or_else(|| anyhow!("no commit checked out"))? {
+            (commit, true) => format!("{commit}{}", history::DIRTY),
+            (commit, false) => commit,
+        },
+    };
+
+    let comparisons = history::compare(&history::load(&path)?, &baseline, &current)?;
+    if comparisons.is_empty() {
+        return Err(anyhow!("no runs on the same input at both {baseline} and {current}"));
+    }
+
+    println!(
+        "{:>3}  {:>5}  {:<16}  {:>16}  {:>16}  {:>8}",
+        "Day", "Part", "Input", &baseline, &current, "Change"
+    );
+    let mut regressions = 0;
+    for c in &comparisons {
+        let median = |m: history::Median| format!("{:?} ({})", m.time, m.runs);
+        let input = match c.stream {
+            true => format!("{} (stream)", c.input),
+            false => c.input.clone(),
+        };
+        let regressed = c.regressed(threshold / 100.0);
+        regressions += usize::from(regressed);
+        println!(
+            "{:>3}  {:>5}  {:<16}  {:>16}  {:>16}  {:>+7.1}%{}",
+            c.day,
+            c.stage,
+            input,
+            median(c.baseline),
+            median(c.current),
+            c.change() * 100.0,
+            if regressed { "  regressed" } else { "" }
+        );
+    }
+    println!("{regressions} of {} stage(s) regressed by more than {threshold}%", comparisons.len());
+
+    Ok(regressions == 0)
+}
+
+/// Solves every day on its actual input and regenerates the results table of
+/// the README with the answers' status and the timings.
+fn update_readme() -> Result<()> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: publishing the timings of a debug build");
+    }
+
+    let mut results = Vec::new();
+    for puzzle in days::PUZZLES {
+        let expected = match expected::path(puzzle).exists() {
+            true => expected::load(puzzle)?,
+            false => Vec::new(),
+        };
+        let result = solve(puzzle, "input", None, false);
+        if let Err(e) = &result {
+            eprintln!("error: day {}: {e:#}", puzzle.day);
+        }
+        results.push((puzzle, result, expected));
+    }
+
+    let path = aoc_common::workspace_root().join("README.md");
+    readme::update(&path, &readme::table(&results))?;
+    println!("updated {}", path.display());
+    Ok(())
+}
+
+/// Creates the crate of `day` and prints how to register it in the runner.
+fn new_day(day: u8, title: &str) -> Result<()> {
+    let names = scaffold::Names::new(day, title)?;
+    let dir = scaffold::new_day(aoc_common::workspace_root(), day, &names)?;
+    println!("created {}", dir.display());
+    println!();
+    println!("To run it through the runner, add");
+    println!("    {0} = {{ path = \"../{0}\" }}", names.package);
+    println!("to the dependencies in aoc/Cargo.toml,");
+    println!("    {}::PUZZLE,", names.module);
+    println!("to PUZZLES in aoc/src/days.rs, and");
+    println!("    {}::{},", names.module, names.solution);
+    println!("to bench_days! in aoc/benches/days.rs.");
+
+    Ok(())
+}
+
+fn fetch(client: &Client, puzzle: &Puzzle) -> Result<()> {
+    let path = puzzle.input_path("input");
+    if client.fetch_input(puzzle.day, &path)? {
+        println!("day {}: downloaded {}", puzzle.day, path.display());
+    } else {
+        println!("day {}: using cached {}", puzzle.day, path.display());
+    }
+
+    Ok(())
+}
+
+/// Submits `answer` (or the solved answer) to `part` of `puzzle`, unless it is
+/// already known to be right or wrong, and records the verdict.
+fn submit(
+    client: &Client,
+    puzzle: &Puzzle,
+    part: u8,
+    answer: Option<String>,
+    wait: bool,
+) -> Result<Verdict> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => solve(puzzle, "input", Some(part), false)?.answers.remove(0).value,
+    };
+
+    let correct = if expected::path(puzzle).exists() {
+        expected::load(puzzle)?
+            .into_iter()
+            .find(|e| e.input == "input")
+            .and_then(|e| e.answer(part).map(str::to_string))
+    } else {
+        None
+    };
+    match correct {
+        Some(correct) if correct == answer => return Ok(Verdict::Correct),
+        Some(correct) => {
+            return Err(anyhow!("refusing to submit {answer}: the correct answer is {correct}"))
+        }
+        None => {}
+    }
+
+    if let Some(reason) = submissions::known_wrong(&submissions::load(puzzle)?, part, &answer) {
+        return Err(anyhow!("refusing to submit {answer}: {reason}"));
+    }
+
+    let verdict = loop {
+        match client.submit(puzzle.day, part, &answer)? {
+            Verdict::Wait(seconds) if wait => {
+                eprintln!("answered too recently, retrying in {seconds}s");
+                thread::sleep(Duration::from_secs(seconds));
+            }
+            verdict => break verdict,
+        }
+    };
+
+    if verdict.is_final() {
+        let submission = Submission {
+            part,
+            verdict: verdict.clone(),
+            answer: answer.clone(),
+        };
+        submissions::append(puzzle, &submission)?;
+    }
+    if verdict == Verdict::Correct {
+        expected::record(puzzle, "input", part, &answer)?;
+    }
+
+    Ok(verdict)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_common::trace::init(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            stream,
+            parallel,
+        } => {
+            let mut puzzles = day.puzzles();
+            if let Days::All = day {
+                // Days have different samples, e.g. day 9 has two.
+                puzzles.retain(|puzzle| {
+                    let found = puzzle.resolve_input(&input).is_file();
+                    if !found {
+                        eprintln!("warning: day {}: no input {input}, skipping", puzzle.day);
+                    }
+                    found
+                });
+                if puzzles.is_empty() {
+                    eprintln!("error: no day has an input {input}");
+                    return ExitCode::FAILURE;
+                }
+            }
+            let solve = |puzzle: &'static Puzzle| {
+                let solver = if parallel { puzzle.parallel() } else { *puzzle };
+                (puzzle, solve(&solver, &input, part, stream))
+            };
+            let (results, elapsed) = aoc_common::timed(|| match parallel {
+                true => puzzles.into_par_iter().map(solve).collect::<Vec<_>>(),
+                false => puzzles.into_iter().map(solve).collect::<Vec<_>>(),
+            });
+
+            if format == Format::Text && parallel {
+                print_summary(&results, elapsed);
+                print_allocations(&results);
+            } else if format == Format::Text {
+                print_table(&results);
+                print_allocations(&results);
+            } else {
+                let mut reports = Vec::new();
+                for (puzzle, result) in &results {
+                    match result {
+                        Ok(report) => reports.push((*puzzle, report)),
+                        Err(e) => eprintln!("error: day {}: {e:#}", puzzle.day),
+                    }
+                }
+                if let Err(e) = aoc_common::output::print(format, &reports) {
+                    eprintln!("error: {e:#}");
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if results.iter().all(|(_, result)| result.is_ok()) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Fetch { day, server } => {
+            let client = server.client();
+            let mut status = ExitCode::SUCCESS;
+            for puzzle in day.puzzles() {
+                if let Err(e) = fetch(&client, puzzle) {
+                    eprintln!("error: day {}: {e:#}", puzzle.day);
+                    status = ExitCode::FAILURE;
+                }
+            }
+
+            status
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            wait,
+            server,
+        } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("error: day {day} is not solved");
+                return ExitCode::FAILURE;
+            };
+
+            match submit(&server.client(), puzzle, part, answer, wait) {
+                Ok(Verdict::Correct) => {
+                    println!("day {day} part {part}: correct");
+                    ExitCode::SUCCESS
+                }
+                Ok(verdict) => {
+                    println!("day {day} part {part}: {verdict}");
+                    ExitCode::FAILURE
+                }
+                Err(e) => {
+                    eprintln!("error: {e:#}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Watch { day } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("error: day {day} is not solved");
+                return ExitCode::FAILURE;
+            };
+
+            match watch::watch(puzzle) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("error: {e:#}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Batch { day, dir } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("error: day {day} is not solved");
+                return ExitCode::FAILURE;
+            };
+
+            match batch::run(puzzle, &dir) {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("error: {e:#}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Compare {
+            baseline,
+            current,
+            threshold,
+        } => match compare(&baseline, current.as_deref(), threshold) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("error: {e:#}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Readme => match update_readme() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e:#}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::New { day, title } => match new_day(day, &title) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e:#}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
@@ -0,0 +1,144 @@
+// Advent of Code 2022
+// Results table generated into the README
+
+use crate::scaffold::Names;
+use anyhow::{anyhow, Context, Result};
+use aoc_common::{expected::Expected, Puzzle, Report};
+use std::{fmt::Write, fs, path::Path, time::Duration};
+
+/// Markers around the generated section of the README.
+pub const START: &str = "<!-- results:start -->";
+pub const END: &str = "<!-- results:end -->";
+
+/// Title of a puzzle from the name of its crate, e.g. `Treetop Tree House`
+/// for `day-08-treetop-tree-house`.
+pub fn title(puzzle: &Puzzle) -> String {
+    let words = puzzle.name.get("day-NN-".len()..).unwrap_or_default();
+    Names::new(puzzle.day, words).map_or_else(|_| puzzle.name.to_string(), |names| names.title)
+}
+
+/// Status of an answer compared to the expected one: right, wrong or
+/// unknown. Answers themselves are not published.
+fn status(answer: &str, expected: Option<&str>) -> &'static str {
+    match expected {
+        Some(expected) if expected == answer => "✓",
+        Some(_) => "✗",
+        None => "?",
+    }
+}
+
+/// Renders the results of all days on their actual input as a Markdown table
+/// of answer statuses and timings, given the expected answers of each.
+pub fn table(results: &[(&Puzzle, Result<Report>, Vec<Expected>)]) -> String {
+    let mut s = String::new();
+    s += "| Day | Title | Part 1 | Part 2 | Parse | Solve 1 | Solve 2 |\n";
+    s += "|----:|:------|:------:|:------:|------:|--------:|--------:|\n";
+
+    let mut total = Duration::ZERO;
+    for (puzzle, result, expected) in results {
+        let title = title(puzzle);
+        let report = match result {
+            Ok(report) => report,
+            Err(_) => {
+                let _ = writeln!(s, "| {} | {title} | error | error | | | |", puzzle.day);
+                continue;
+            }
+        };
+
+        let expected = expected.iter().find(|e| e.input == "input");
+        let mut statuses = ["-", "-"];
+        let mut times = [String::new(), String::new()];
+        for answer in &report.answers {
+            let part = usize::from(answer.part) - 1;
+            statuses[part] = status(&answer.value, expected.and_then(|e| e.answer(answer.part)));
+            times[part] = format!("{:.1?}", answer.elapsed);
+            total += answer.elapsed;
+        }
+        total += report.parse;
+
+        let [status1, status2] = statuses;
+        let [time1, time2] = times;
+        let parse = format!("{:.1?}", report.parse);
+        let _ = writeln!(
+            s,
+            "| {} | {title} | {status1} | {status2} | {parse} | {time1} | {time2} |",
+            puzzle.day
+        );
+    }
+
+    let _ = writeln!(s, "\nTotal time: {total:.1?}");
+    s
+}
+
+/// Replaces the section between the [`START`] and [`END`] markers of
+/// `readme` with `section`.
+pub fn splice(readme: &str, section: &str) -> Result<String> {
+    let start = readme
+        .find(START)
+        .ok_or_else(|| anyhow!("missing {START} marker"))?
+        + START.len();
+    let end = readme[start..]
+        .find(END)
+        .ok_or_else(|| anyhow!("missing {END} marker after {START}"))?
+        + start;
+
+    Ok(format!("{}\n{section}{}", &readme[..start], &readme[end..]))
+}
+
+/// Regenerates the results section of the README at `path`.
+pub fn update(path: &Path, section: &str) -> Result<()> {
+    let readme =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let updated = splice(&readme, section).with_context(|| format!("in {}", path.display()))?;
+    fs::write(path, updated).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::Answer;
+
+    #[test]
+    fn titles() {
+        let puzzle = day_08_treetop_tree_house::PUZZLE;
+        assert_eq!(title(&puzzle), "Treetop Tree House");
+    }
+
+    #[test]
+    fn rows() {
+        let puzzle = day_08_treetop_tree_house::PUZZLE;
+        let answer = |part, value: &str| Answer {
+            part,
+            value: value.to_string(),
+            elapsed: Duration::from_micros(150),
+            cpu: Duration::ZERO,
+            alloc: None,
+        };
+        let report = Report {
+            parse: Duration::from_micros(20),
+            parse_cpu: Duration::ZERO,
+            parse_alloc: None,
+            answers: vec![answer(1, "21"), answer(2, "7")],
+        };
+        let expected = Expected {
+            input: "input".to_string(),
+            answers: [Some("21".to_string()), Some("8".to_string())],
+        };
+
+        let table = table(&[(&puzzle, Ok(report), vec![expected])]);
+        let row = table.lines().nth(2).unwrap();
+        assert_eq!(row, "| 8 | Treetop Tree House | ✓ | ✗ | 20.0µs | 150.0µs | 150.0µs |");
+        assert!(table.ends_with("Total time: 320.0µs\n"));
+    }
+
+    #[test]
+    fn splice_between_markers() {
+        let readme = format!("# AoC\n\n{START}\nold\n{END}\n\nMore\n");
+        let updated = splice(&readme, "new\n").unwrap();
+        assert_eq!(updated, format!("# AoC\n\n{START}\nnew\n{END}\n\nMore\n"));
+        assert_eq!(splice(&updated, "new\n").unwrap(), updated);
+
+        assert!(splice("# AoC\n", "new\n").is_err());
+        assert!(splice(&format!("{END}\n{START}\n"), "new\n").is_err());
+    }
+}
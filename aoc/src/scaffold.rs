@@ -0,0 +1,197 @@
+// Advent of Code 2022
+// Scaffolding of new day crates
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::expected::{self, Expected};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Names of a day derived from its puzzle title.
+pub struct Names {
+    /// Crate name, e.g. `day-07-no-space-left-on-device`.
+    pub package: String,
+    /// Crate name as used in paths, e.g. `day_07_no_space_left_on_device`.
+    pub module: String,
+    /// Name of the solution type, e.g. `NoSpaceLeftOnDevice`.
+    pub solution: String,
+    /// Title of the puzzle, e.g. `No Space Left On Device`.
+    pub title: String,
+}
+
+impl Names {
+    /// Derives the names of `day` from `title`, given in any case with words
+    /// separated by spaces, dashes or underscores.
+    pub fn new(day: u8, title: &str) -> Result<Self> {
+        let words = title
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect::<Vec<_>>();
+        if words.is_empty() || !title.is_ascii() {
+            return Err(anyhow!("Invalid title '{}'", title));
+        }
+
+        let capitalized = words
+            .iter()
+            .map(|w| w[..1].to_ascii_uppercase() + &w[1..])
+            .collect::<Vec<_>>();
+        let package = format!("day-{day:02}-{}", words.join("-"));
+
+        Ok(Self {
+            module: package.replace('-', "_"),
+            package,
+            solution: capitalized.concat(),
+            title: capitalized.join(" "),
+        })
+    }
+}
+
+fn cargo_toml(names: &Names) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+anyhow = "1.0.66"
+aoc-common = {{ path = "../aoc-common" }}
+"#,
+        names.package
+    )
+}
+
+fn lib_rs(day: u8, names: &Names) -> String {
+    let Names { solution, title, .. } = names;
+    format!(
+        r#"// Advent of Code 2022
+// Day {day}: {title}
+
+use anyhow::{{anyhow, Result}};
+use aoc_common::{{error::numbered_lines, ParseError, Solution}};
+
+/// Solution to day {day}: {title}.
+pub struct {solution};
+
+impl Solution for {solution} {{
+    /// Lines of the puzzle input.
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(numbered_lines(input).map(|(_, line)| line.to_string()).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<u64> {{ Err(anyhow!("not solved yet")) }}
+
+    fn part2(_input: &Self::Input) -> Result<u64> {{ Err(anyhow!("not solved yet")) }}
+}}
+
+aoc_common::puzzle!({solution});
+"#
+    )
+}
+
+fn main_rs(day: u8, names: &Names) -> String {
+    format!(
+        r#"// Advent of Code 2022
+// Day {day}
+
+use {}::PUZZLE;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {{ aoc_common::run(&PUZZLE) }}
+"#,
+        names.module
+    )
+}
+
+fn answers_rs(names: &Names) -> String {
+    format!(
+        r#"use {}::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> {{ aoc_common::expected::check(&PUZZLE) }}
+"#,
+        names.module
+    )
+}
+
+/// Creates the crate of `day` in the workspace at `root`, with a solution
+/// skeleton, an empty sample input, unknown expected answers and a test
+/// checking them. The actual input is left for `fetch` to download. Returns
+/// the directory of the crate.
+pub fn new_day(root: &Path, day: u8, names: &Names) -> Result<PathBuf> {
+    let prefix = format!("day-{day:02}-");
+    for entry in fs::read_dir(root).with_context(|| format!("failed to read {}", root.display()))? {
+        let name = entry?.file_name();
+        if name.to_string_lossy().starts_with(&prefix) {
+            return Err(anyhow!("day {} already exists: {}", day, name.to_string_lossy()));
+        }
+    }
+
+    let dir = root.join(&names.package);
+    let sample = Expected {
+        input: "sample".to_string(),
+        answers: [None, None],
+    };
+    let files = [
+        ("Cargo.toml", cargo_toml(names)),
+        ("src/lib.rs", lib_rs(day, names)),
+        ("src/main.rs", main_rs(day, names)),
+        ("tests/answers.rs", answers_rs(names)),
+        ("input/sample.txt", String::new()),
+        (expected::FILE_NAME, expected::render(&[sample])),
+    ];
+    for (name, contents) in files {
+        let path = match name {
+            expected::FILE_NAME => dir.join("input").join(name),
+            _ => dir.join(name),
+        };
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| fs::write(&path, contents))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn names() {
+        let names = Names::new(7, "No Space Left On_device").unwrap();
+        assert_eq!(names.package, "day-07-no-space-left-on-device");
+        assert_eq!(names.module, "day_07_no_space_left_on_device");
+        assert_eq!(names.solution, "NoSpaceLeftOnDevice");
+        assert_eq!(names.title, "No Space Left On Device");
+
+        assert!(Names::new(7, " - ").is_err());
+    }
+
+    #[test]
+    fn scaffold_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        let names = Names::new(7, "no-space-left-on-device").unwrap();
+        let dir = new_day(&root, 7, &names).unwrap();
+        assert_eq!(dir, root.join("day-07-no-space-left-on-device"));
+        for file in ["Cargo.toml", "src/lib.rs", "src/main.rs", "tests/answers.rs"] {
+            assert!(dir.join(file).is_file(), "{file} is missing");
+        }
+        let answers = fs::read_to_string(dir.join("input/answers.txt")).unwrap();
+        assert_eq!(expected::parse(&answers).unwrap()[0].input, "sample");
+
+        assert!(new_day(&root, 7, &Names::new(7, "other").unwrap()).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+// Advent of Code 2022
+// Log of answers submitted to the website
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::Puzzle;
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Name of the file in a day's `input` directory logging submitted answers
+/// to the actual input, one `part verdict answer` line per submission.
+pub const FILE_NAME: &str = "submissions.txt";
+
+/// Verdict of the website on a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently; retry after this many seconds.
+    Wait(u64),
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Parses the verdict from the HTML page returned on submission.
+    pub fn from_html(html: &str) -> Result<Self> {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Ok(Verdict::TooHigh)
+            } else if text.contains("your answer is too low") {
+                Ok(Verdict::TooLow)
+            } else {
+                Ok(Verdict::Wrong)
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Ok(Verdict::Wait(wait_seconds(&text).unwrap_or(60)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err(anyhow!("unrecognized response: {}", text.trim()))
+        }
+    }
+
+    /// Whether the verdict is about the answer itself, and so worth logging.
+    pub fn is_final(&self) -> bool { !matches!(self, Verdict::Wait(_) | Verdict::WrongLevel) }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wait(seconds) => write!(f, "wait-{seconds}s"),
+            Verdict::WrongLevel => write!(f, "wrong-level"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            _ => Err(anyhow!("Invalid verdict '{}'", s)),
+        }
+    }
+}
+
+/// Text of the `<article>` element holding the verdict, without markup.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Parses the time left from e.g. "You have 1m 23s left to wait".
+fn wait_seconds(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end].split_whitespace().try_fold(0, |acc, v| {
+        let (n, unit) = v.split_at(v.len() - 1);
+        let n = n.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(acc + n * 3600),
+            "m" => Some(acc + n * 60),
+            "s" => Some(acc + n),
+            _ => None,
+        }
+    })
+}
+
+/// An answer submitted for the actual input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+fn path(puzzle: &Puzzle) -> PathBuf { PathBuf::from(puzzle.input_dir).join(FILE_NAME) }
+
+/// Loads the submissions logged for `puzzle`.
+pub fn load(puzzle: &Puzzle) -> Result<Vec<Submission>> {
+    let path = path(puzzle);
+    let s = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).context(format!("failed to read {}", path.display())),
+    };
+
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [part, verdict, answer] = fields[..] else {
+                return Err(anyhow!("invalid submission '{}' in {}", line, path.display()));
+            };
+            Ok(Submission {
+                part: part.parse()?,
+                verdict: verdict.parse()?,
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Appends `submission` to the log of `puzzle`.
+pub fn append(puzzle: &Puzzle, submission: &Submission) -> Result<()> {
+    let path = path(puzzle);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    writeln!(
+        file,
+        "{} {} {}",
+        submission.part, submission.verdict, submission.answer
+    )?;
+
+    Ok(())
+}
+
+/// Reason not to submit `answer` to `part`, judging by earlier submissions.
+pub fn known_wrong(submissions: &[Submission], part: u8, answer: &str) -> Option<String> {
+    let value = answer.parse::<i64>().ok();
+    for s in submissions.iter().filter(|s| s.part == part) {
+        let bound = s.answer.parse::<i64>().ok();
+        match (&s.verdict, value, bound) {
+            (Verdict::Correct, ..) if s.answer != answer => {
+                return Some(format!("the correct answer is {}", s.answer));
+            }
+            (Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow, ..) if s.answer == answer => {
+                return Some(format!("{answer} was already rejected as {}", s.verdict));
+            }
+            (Verdict::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                return Some(format!("{} was too high", s.answer));
+            }
+            (Verdict::TooLow, Some(value), Some(bound)) if value <= bound => {
+                return Some(format!("{} was too low", s.answer));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn verdicts() {
+        let correct = page("That's the right answer! You are <em>one gold star</em> closer.");
+        assert_eq!(Verdict::from_html(&correct).unwrap(), Verdict::Correct);
+
+        let high =
+            page("That's not the right answer; your answer is too high.  If you're stuck...");
+        assert_eq!(Verdict::from_html(&high).unwrap(), Verdict::TooHigh);
+
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(Verdict::from_html(&low).unwrap(), Verdict::TooLow);
+
+        let wrong = page("That's not the right answer.  If you're stuck...");
+        assert_eq!(Verdict::from_html(&wrong).unwrap(), Verdict::Wrong);
+
+        let wait = page("You gave an answer too recently. You have 1m 23s left to wait.");
+        assert_eq!(Verdict::from_html(&wait).unwrap(), Verdict::Wait(83));
+
+        let level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(Verdict::from_html(&level).unwrap(), Verdict::WrongLevel);
+
+        assert!(Verdict::from_html(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn refuse_known_wrong() {
+        let submissions = [
+            Submission {
+                part: 1,
+                verdict: Verdict::TooHigh,
+                answer: "500".to_string(),
+            },
+            Submission {
+                part: 1,
+                verdict: Verdict::TooLow,
+                answer: "100".to_string(),
+            },
+            Submission {
+                part: 2,
+                verdict: Verdict::Wrong,
+                answer: "ABC".to_string(),
+            },
+        ];
+
+        assert!(known_wrong(&submissions, 1, "500").is_some());
+        assert!(known_wrong(&submissions, 1, "600").is_some());
+        assert!(known_wrong(&submissions, 1, "50").is_some());
+        assert!(known_wrong(&submissions, 1, "300").is_none());
+        assert!(known_wrong(&submissions, 2, "ABC").is_some());
+        assert!(known_wrong(&submissions, 2, "ABD").is_none());
+    }
+}
@@ -0,0 +1,274 @@
+// Advent of Code 2022
+// Re-solving a day whenever its inputs or sources change
+
+use crate::submissions;
+use anyhow::{Context, Result};
+use aoc_common::{
+    expected::{self, Expected},
+    Puzzle,
+};
+use console::{style, Term};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::Duration,
+};
+
+/// Time to wait for more changes after one, since editors and cargo write
+/// files in several steps.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// Answer to one part for one input, or the error solving it.
+pub type Outcome = Result<String, String>;
+
+/// Names of the input files in `dir`, samples first and the actual input
+/// last, without `.txt` extension.
+pub fn inputs(dir: &Path) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if let Some(stem) = name.strip_suffix(".txt") {
+            if ![expected::FILE_NAME, submissions::FILE_NAME].contains(&name) {
+                names.push(stem.to_string());
+            }
+        }
+    }
+
+    names.sort_by_key(|name| (name == "input", name.clone()));
+    Ok(names)
+}
+
+/// Solves `part` of the input called `name`.
+fn solve(puzzle: &Puzzle, name: &str, part: u8) -> Outcome {
+    let input = aoc_common::read_input(puzzle, Some(name)).map_err(|e| format!("{e:#}"))?;
+    solve_text(puzzle, &input, part)
+}
+
+/// Solves `part` of `input`, catching panics so a bad input doesn't end the
+/// run.
+pub fn solve_text(puzzle: &Puzzle, input: &str, part: u8) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(|| (puzzle.solve)(input, Some(part)))) {
+        Ok(Ok(mut report)) => Ok(report.answers.remove(0).value),
+        Ok(Err(e)) => Err(format!("{e:#}")),
+        Err(payload) => {
+            let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+                (Some(s), _) => s.to_string(),
+                (_, Some(s)) => s.clone(),
+                (None, None) => "unknown cause".to_string(),
+            };
+            Err(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// Text of the cell showing `outcome`, and whether it is right compared to
+/// the `expected` answer, if known.
+pub fn cell(outcome: &Outcome, expected: Option<&str>) -> (String, Option<bool>) {
+    match (outcome, expected) {
+        (Ok(answer), Some(correct)) if answer == correct => (format!("{answer} ✓"), Some(true)),
+        (Ok(answer), Some(correct)) => (format!("{answer} ✗ (expected {correct})"), Some(false)),
+        (Ok(answer), None) => (answer.clone(), None),
+        (Err(_), _) => ("error".to_string(), Some(false)),
+    }
+}
+
+/// Renders the outcomes of both parts as a table with one column per input,
+/// answers matching the expected ones in green and others in red, followed
+/// by the errors.
+pub fn render(inputs: &[String], outcomes: &[[Outcome; 2]], expected: &[Expected]) -> String {
+    let cells = inputs
+        .iter()
+        .zip(outcomes)
+        .map(|(input, outcomes)| {
+            let expected = expected.iter().find(|e| &e.input == input);
+            [1, 2].map(|part| {
+                let correct = expected.and_then(|e| e.answer(part));
+                cell(&outcomes[usize::from(part) - 1], correct)
+            })
+        })
+        .collect::<Vec<_>>();
+    let widths = inputs
+        .iter()
+        .zip(&cells)
+        .map(|(input, cells)| {
+            let widest = cells.iter().map(|(text, _)| text.chars().count()).max();
+            widest.unwrap_or(0).max(input.len())
+        })
+        .collect::<Vec<_>>();
+
+    let mut s = "Part".to_string();
+    for (input, width) in inputs.iter().zip(&widths) {
+        s += &format!("  {input:<width$}");
+    }
+    for part in 0..2 {
+        s += &format!("\n{:>4}", part + 1);
+        for ((text, verdict), width) in cells.iter().map(|cells| &cells[part]).zip(&widths) {
+            let padded = format!("{text:<width$}");
+            let styled = match verdict {
+                Some(true) => style(padded).green(),
+                Some(false) => style(padded).red(),
+                None => style(padded),
+            };
+            s += &format!("  {styled}");
+        }
+    }
+    s += "\n";
+
+    for (input, outcomes) in inputs.iter().zip(outcomes) {
+        for (part, outcome) in outcomes.iter().enumerate() {
+            if let Err(e) = outcome {
+                s += &format!("\n{input}, part {}: {e}\n", part + 1);
+            }
+        }
+    }
+    s
+}
+
+/// Solves every input of `puzzle` in `input_dir` and renders the table of
+/// answers.
+fn solve_all_inputs(puzzle: &Puzzle, input_dir: &Path) -> Result<String> {
+    let inputs = inputs(input_dir)?;
+    let outcomes = inputs
+        .iter()
+        .map(|name| [1, 2].map(|part| solve(puzzle, name, part)))
+        .collect::<Vec<_>>();
+    let expected = match expected::path(puzzle).exists() {
+        true => expected::load(puzzle)?,
+        false => Vec::new(),
+    };
+
+    Ok(render(&inputs, &outcomes, &expected))
+}
+
+/// Rebuilds the runner and restarts it with the same arguments, picking up
+/// the changed sources. Only returns if the build fails, leaving the current
+/// one running.
+fn rebuild() -> Result<()> {
+    // Once replaced by the build, the running executable has no path anymore.
+    let exe = env::current_exe()?;
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut build = Command::new(cargo);
+    build.current_dir(aoc_common::workspace_root()).args(["build", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        build.args(["--features", "alloc-stats"]);
+    }
+    if !build.status().context("failed to run cargo")?.success() {
+        return Ok(());
+    }
+
+    let mut restart = Command::new(exe);
+    restart.args(env::args_os().skip(1));
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(restart.exec()).context("failed to restart")
+    }
+    #[cfg(not(unix))]
+    {
+        let status = restart.status().context("failed to restart")?;
+        std::process::exit(status.code().unwrap_or(1))
+    }
+}
+
+/// Paths changed by `event`, ignoring mere accesses.
+fn changed(event: notify::Result<Event>) -> Result<Vec<PathBuf>> {
+    let event = event?;
+    Ok(match event.kind {
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => event.paths,
+        _ => Vec::new(),
+    })
+}
+
+/// Solves every input of `puzzle` and shows the answers, again whenever a
+/// file in its `input` directory changes. Changes to its sources rebuild and
+/// restart the runner. Runs until interrupted.
+pub fn watch(puzzle: &Puzzle) -> Result<()> {
+    let input_dir = Path::new(puzzle.input_dir);
+    let src_dir = input_dir.parent().unwrap().join("src");
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(input_dir, RecursiveMode::NonRecursive)?;
+    watcher.watch(&src_dir, RecursiveMode::Recursive)?;
+
+    let term = Term::stdout();
+    let mut solve_all = true;
+    loop {
+        if solve_all {
+            let table = solve_all_inputs(puzzle, input_dir);
+            if term.is_term() {
+                let _ = term.clear_screen();
+            }
+            println!("{}\n", style(puzzle.name).bold());
+            match table {
+                Ok(table) => println!("{table}"),
+                // Likely a file caught half-saved, fixed by the next change.
+                Err(e) => println!("{}\n", style(format!("error: {e:#}")).red()),
+            }
+        }
+        println!("watching {} and {}", input_dir.display(), src_dir.display());
+
+        let mut paths = Vec::new();
+        while paths.is_empty() {
+            paths = changed(rx.recv()?)?;
+        }
+        while let Ok(event) = rx.recv_timeout(SETTLE) {
+            paths.extend(changed(event)?);
+        }
+
+        // Keep the build errors on screen until the next change.
+        solve_all = !paths.iter().any(|path| path.starts_with(&src_dir));
+        if !solve_all {
+            rebuild()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn samples_before_input() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["input.txt", "sample2.txt", "answers.txt", "sample1.txt", "notes.md"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        assert_eq!(inputs(&dir).unwrap(), ["sample1", "sample2", "input"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn table() {
+        let inputs = ["sample".to_string(), "input".to_string()];
+        let outcomes = [
+            [Ok("13".to_string()), Ok("1".to_string())],
+            [Ok("6190".to_string()), Err("not solved yet".to_string())],
+        ];
+        let expected = [Expected {
+            input: "sample".to_string(),
+            answers: [Some("13".to_string()), Some("36".to_string())],
+        }];
+
+        let table = console::strip_ansi_codes(&render(&inputs, &outcomes, &expected)).into_owned();
+        assert_eq!(
+            table,
+            "Part  sample             input\n   \
+                1  13 ✓               6190 \n   \
+                2  1 ✗ (expected 36)  error\n\
+             \ninput, part 2: not solved yet\n"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+// Advent of Code 2022
+// Day 1: Calorie Counting
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    parse::{self, blocks, number},
+    stream::for_each_line,
+    ParseError, Solution, Streaming,
+};
+use std::{cmp::Reverse, collections::BinaryHeap, io::BufRead};
+
+/// Parses the calories of one item, the input line with number `n`.
+fn parse_calories(n: usize, line: &str) -> Result<u32, ParseError> { parse::line(n, line, number) }
+
+/// Solution to day 1: Calorie Counting.
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    type Input = BinaryHeap<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        blocks(input)
+            .map(|elf| elf.iter().map(|&(n, line)| parse_calories(n, line)).sum())
+            .collect()
+    }
+
+    fn part1(pq: &Self::Input) -> Result<u32> {
+        pq.peek().copied().ok_or_else(|| anyhow!("no elves in input"))
+    }
+
+    fn part2(pq: &Self::Input) -> Result<u32> {
+        let mut pq = pq.clone();
+        (0..3)
+            .map(|_| pq.pop().ok_or_else(|| anyhow!("fewer than 3 elves in input")))
+            .sum()
+    }
+}
+
+impl Streaming for CalorieCounting {
+    /// Keeps only the three largest totals seen so far.
+    fn stream(reader: &mut dyn BufRead) -> Result<(u32, u32)> {
+        fn add(top: &mut BinaryHeap<Reverse<u32>>, elf: Option<u32>) {
+            top.extend(elf.map(Reverse));
+            if top.len() > 3 {
+                top.pop();
+            }
+        }
+
+        let mut top = BinaryHeap::new();
+        let mut elf = None;
+        for_each_line(reader, |n, line| {
+            match line.is_empty() {
+                true => add(&mut top, elf.take()),
+                false => elf = Some(elf.unwrap_or(0) + parse_calories(n, line)?),
+            }
+            Ok(())
+        })?;
+        add(&mut top, elf);
+
+        let pq = top.into_iter().map(|Reverse(calories)| calories).collect();
+        Ok((Self::part1(&pq)?, Self::part2(&pq)?))
+    }
+}
+
+aoc_common::puzzle!(CalorieCounting, streaming);
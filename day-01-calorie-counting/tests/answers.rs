@@ -0,0 +1,4 @@
+use day_01_calorie_counting::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
@@ -0,0 +1,176 @@
+// Advent of Code 2022
+// Day 2: Rock Paper Scissors
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    error::numbered_lines,
+    parse::{self, from_word, spaced},
+    stream::for_each_line,
+    ParseError, Solution, Streaming,
+};
+use nom::{sequence::pair, Parser};
+use std::io::BufRead;
+
+/// Shape played in a round.
+#[derive(Copy, Clone, Debug)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// Outcome of a round the player has to achieve.
+#[derive(Copy, Clone, Debug)]
+pub enum Goal {
+    Win,
+    Tie,
+    Loss,
+}
+
+/// Second column of the strategy guide, whose meaning differs between parts.
+#[derive(Copy, Clone, Debug)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+impl TryFrom<&str> for Choice {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "A" => Ok(Choice::Rock),
+            "B" => Ok(Choice::Paper),
+            "C" => Ok(Choice::Scissors),
+            _ => Err(anyhow!("Invalid character '{}'", value)),
+        }
+    }
+}
+
+impl TryFrom<&str> for Column {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "X" => Ok(Column::X),
+            "Y" => Ok(Column::Y),
+            "Z" => Ok(Column::Z),
+            _ => Err(anyhow!("Invalid character '{}'", value)),
+        }
+    }
+}
+
+impl From<Column> for Choice {
+    fn from(column: Column) -> Self {
+        match column {
+            Column::X => Choice::Rock,
+            Column::Y => Choice::Paper,
+            Column::Z => Choice::Scissors,
+        }
+    }
+}
+
+impl From<Column> for Goal {
+    fn from(column: Column) -> Self {
+        match column {
+            Column::X => Goal::Loss,
+            Column::Y => Goal::Tie,
+            Column::Z => Goal::Win,
+        }
+    }
+}
+
+/// One line of the encrypted strategy guide.
+#[derive(Debug)]
+pub struct Round {
+    pub opponent: Choice,
+    pub player: Column,
+}
+
+impl Round {
+    /// Parses a round like `A Y`, the input line with number `n`.
+    pub fn parse(n: usize, line: &str) -> Result<Self, ParseError> {
+        let round = pair(from_word(Choice::try_from), spaced(from_word(Column::try_from)));
+        parse::line(n, line, round.map(|(opponent, player)| Round { opponent, player }))
+    }
+
+    /// Score when the second column is the shape to play (part 1).
+    pub fn score_choice(&self) -> u32 {
+        match self.opponent {
+            Choice::Rock => match Choice::from(self.player) {
+                Choice::Rock => 1 + 3,
+                Choice::Paper => 2 + 6,
+                Choice::Scissors => 3,
+            },
+            Choice::Paper => match Choice::from(self.player) {
+                Choice::Rock => 1,
+                Choice::Paper => 2 + 3,
+                Choice::Scissors => 3 + 6,
+            },
+            Choice::Scissors => match Choice::from(self.player) {
+                Choice::Rock => 1 + 6,
+                Choice::Paper => 2,
+                Choice::Scissors => 3 + 3,
+            },
+        }
+    }
+
+    /// Score when the second column is the outcome to aim for (part 2).
+    pub fn score_goal(&self) -> u32 {
+        match Goal::from(self.player) {
+            Goal::Win => match self.opponent {
+                Choice::Rock => 2 + 6,
+                Choice::Paper => 3 + 6,
+                Choice::Scissors => 1 + 6,
+            },
+            Goal::Tie => match self.opponent {
+                Choice::Rock => 1 + 3,
+                Choice::Paper => 2 + 3,
+                Choice::Scissors => 3 + 3,
+            },
+            Goal::Loss => match self.opponent {
+                Choice::Rock => 3,
+                Choice::Paper => 1,
+                Choice::Scissors => 2,
+            },
+        }
+    }
+}
+
+/// Solution to day 2: Rock Paper Scissors.
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    type Input = Vec<Round>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input).map(|(n, line)| Round::parse(n, line)).collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<u32> {
+        Ok(rounds.iter().map(Round::score_choice).sum())
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<u32> {
+        Ok(rounds.iter().map(Round::score_goal).sum())
+    }
+}
+
+impl Streaming for RockPaperScissors {
+    fn stream(reader: &mut dyn BufRead) -> Result<(u32, u32)> {
+        let mut scores = (0, 0);
+        for_each_line(reader, |n, line| {
+            let round = Round::parse(n, line)?;
+            scores.0 += round.score_choice();
+            scores.1 += round.score_goal();
+            Ok(())
+        })?;
+
+        Ok(scores)
+    }
+}
+
+aoc_common::puzzle!(RockPaperScissors, streaming);
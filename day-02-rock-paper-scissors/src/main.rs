@@ -0,0 +1,7 @@
+// Advent of Code 2022
+// Day 2: Rock Paper Scissors
+
+use day_02_rock_paper_scissors::PUZZLE;
+use std::process::ExitCode;
+
+fn main() -> ExitCode { aoc_common::run(&PUZZLE) }
@@ -0,0 +1,4 @@
+use day_02_rock_paper_scissors::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
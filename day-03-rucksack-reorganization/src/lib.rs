@@ -0,0 +1,114 @@
+// Advent of Code 2022
+// Day 3
+
+use anyhow::{anyhow, Result};
+use aoc_common::{error::numbered_lines, stream::for_each_line, ParseError, Solution, Streaming};
+use itertools::Itertools;
+use std::{collections::HashSet, io::BufRead};
+
+/// Priority of an item type: `a` through `z` are 1 to 26, `A` through `Z`
+/// are 27 to 52. Other characters are not items.
+pub fn item_priority(item: &u8) -> Option<u64> {
+    match item {
+        b'a'..=b'z' => Some((item - 96) as u64),
+        b'A'..=b'Z' => Some((item - 38) as u64),
+        _ => None,
+    }
+}
+
+/// Parses the item priorities of a rucksack, the input line with number `n`.
+pub fn parse_rucksack(n: usize, line: &str) -> Result<Vec<u64>, ParseError> {
+    let prios = line
+        .char_indices()
+        .map(|(i, c)| {
+            u8::try_from(c).ok().and_then(|c| item_priority(&c)).ok_or_else(|| {
+                let item = &line[i..i + c.len_utf8()];
+                ParseError::at(n, line, item, format!("Invalid item '{c}'"))
+            })
+        })
+        .collect::<Result<Vec<u64>, _>>()?;
+    if prios.len() % 2 != 0 {
+        return Err(ParseError::line(n, line, "Odd number of items in rucksack"));
+    }
+
+    Ok(prios)
+}
+
+/// Priority of the item type in both compartments of a rucksack.
+pub fn shared_item(prios: &[u64]) -> Result<u64> {
+    let first: HashSet<_> = prios[..prios.len() / 2].iter().collect();
+    let second: HashSet<_> = prios[prios.len() / 2..].iter().collect();
+
+    first
+        .intersection(&second)
+        .next()
+        .map(|&&prio| prio)
+        .ok_or_else(|| anyhow!("no item shared by both compartments"))
+}
+
+/// Priority of the badge, the item type carried by all elves of a group.
+pub fn badge<'a>(group: impl IntoIterator<Item = &'a Vec<u64>>) -> Result<u64> {
+    let badges: Vec<HashSet<u64>> = group
+        .into_iter()
+        .map(|prios| prios.iter().copied().collect())
+        .collect();
+    if badges.len() != 3 {
+        return Err(anyhow!("incomplete group of {} elves", badges.len()));
+    }
+
+    badges[0]
+        .intersection(&badges[1])
+        .copied()
+        .collect::<HashSet<u64>>()
+        .intersection(&badges[2])
+        .next()
+        .copied()
+        .ok_or_else(|| anyhow!("no badge shared by the group"))
+}
+
+/// Solution to day 3: Rucksack Reorganization.
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    /// Item priorities of every rucksack.
+    type Input = Vec<Vec<u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input).map(|(n, line)| parse_rucksack(n, line)).collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<u64> {
+        rucksacks.iter().map(|prios| shared_item(prios)).sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<u64> {
+        rucksacks.iter().chunks(3).into_iter().map(badge).sum()
+    }
+}
+
+impl Streaming for RucksackReorganization {
+    /// Keeps only the rucksacks of the current group.
+    fn stream(reader: &mut dyn BufRead) -> Result<(u64, u64)> {
+        let mut sums = (0, 0);
+        let mut group = Vec::with_capacity(3);
+        for_each_line(reader, |n, line| {
+            let prios = parse_rucksack(n, line)?;
+            sums.0 += shared_item(&prios)?;
+            group.push(prios);
+            if group.len() == 3 {
+                sums.1 += badge(&group)?;
+                group.clear();
+            }
+            Ok(())
+        })?;
+        if !group.is_empty() {
+            sums.1 += badge(&group)?;
+        }
+
+        Ok(sums)
+    }
+}
+
+aoc_common::puzzle!(RucksackReorganization, streaming);
@@ -0,0 +1,7 @@
+// Advent of Code 2022
+// Day 3
+
+use day_03_rucksack_reorganization::PUZZLE;
+use std::process::ExitCode;
+
+fn main() -> ExitCode { aoc_common::run(&PUZZLE) }
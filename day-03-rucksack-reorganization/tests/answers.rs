@@ -0,0 +1,4 @@
+use day_03_rucksack_reorganization::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
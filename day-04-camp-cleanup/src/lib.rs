@@ -0,0 +1,67 @@
+// Advent of Code 2022
+// Day 4
+
+use anyhow::Result;
+use aoc_common::{
+    error::numbered_lines,
+    parse::{self, literal, range},
+    stream::for_each_line,
+    ParseError, Solution, Streaming,
+};
+use nom::sequence::separated_pair;
+use std::{io::BufRead, ops::RangeInclusive};
+
+/// Section assignments of a pair of elves.
+pub type Pair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+/// Whether one assignment of the pair fully contains the other.
+pub fn complete_overlap((a, b): &Pair) -> bool {
+    a.start() <= b.start() && a.end() >= b.end() || a.start() >= b.start() && a.end() <= b.end()
+}
+
+/// Whether the assignments of the pair overlap at all.
+pub fn partial_overlap((a, b): &Pair) -> bool { a.end() >= b.start() && a.start() <= b.end() }
+
+/// Parses a section assignment pair like `2-4,6-8`, the input line with
+/// number `n`.
+pub fn parse_pair(n: usize, line: &str) -> Result<Pair, ParseError> {
+    parse::line(n, line, separated_pair(range, literal(","), range))
+}
+
+/// Solution to day 4: Camp Cleanup.
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    /// Section assignment pairs.
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input).map(|(n, line)| parse_pair(n, line)).collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<usize> {
+        Ok(pairs.iter().filter(|e| complete_overlap(e)).count())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<usize> {
+        Ok(pairs.iter().filter(|e| partial_overlap(e)).count())
+    }
+}
+
+impl Streaming for CampCleanup {
+    fn stream(reader: &mut dyn BufRead) -> Result<(usize, usize)> {
+        let mut counts = (0, 0);
+        for_each_line(reader, |n, line| {
+            let e = parse_pair(n, line)?;
+            counts.0 += usize::from(complete_overlap(&e));
+            counts.1 += usize::from(partial_overlap(&e));
+            Ok(())
+        })?;
+
+        Ok(counts)
+    }
+}
+
+aoc_common::puzzle!(CampCleanup, streaming);
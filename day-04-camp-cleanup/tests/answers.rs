@@ -0,0 +1,4 @@
+use day_04_camp_cleanup::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
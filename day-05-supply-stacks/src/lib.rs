@@ -0,0 +1,220 @@
+// Advent of Code 2022
+// Day 5
+
+use anyhow::{anyhow, Result};
+use aoc_common::{
+    parse::{self, blocks, keyed, number, spaced},
+    ParseError, Solution,
+};
+use nom::{sequence::tuple, Parser};
+use tracing::{debug, trace};
+
+/// Reads the drawing of the stacks into one vector of crates per stack,
+/// bottom crate first. The number of stacks comes from the row of stack
+/// numbers below the crates, since rows of crates may lack the trailing empty
+/// ones.
+pub fn scan_stacks(input: &[&[u8]]) -> Vec<Vec<u8>> {
+    let Some((numbers, layers)) = input.split_last() else {
+        return Vec::new();
+    };
+    let nstacks = numbers
+        .split(u8::is_ascii_whitespace)
+        .filter(|number| !number.is_empty())
+        .count();
+    let mut stacks: Vec<Vec<u8>> = (0..nstacks).map(|_| Vec::new()).collect();
+    for layer in layers.iter().rev() {
+        for (s, stack) in stacks.iter_mut().enumerate() {
+            let c = layer.get(s * 4 + 1).copied().unwrap_or(b' ');
+            if c == b' ' {
+                continue;
+            }
+            stack.push(c);
+        }
+    }
+
+    stacks
+}
+
+/// CrateMover 9000: moves crates one at a time.
+pub fn move_crate(stacks: &mut [Vec<u8>], from: usize, to: usize, n: usize) {
+    for _ in 0..n {
+        let t = stacks[from].pop().unwrap();
+        stacks[to].push(t);
+    }
+}
+
+/// CrateMover 9001: moves crates all at once, retaining their order.
+pub fn move_crates(stacks: &mut [Vec<u8>], from: usize, to: usize, n: usize) {
+    let l = stacks[from].len() - n;
+    let lifted = stacks[from].split_off(l);
+    stacks[to].extend(lifted);
+}
+
+/// A rearrangement step, with zero-based stack indices.
+#[derive(Debug)]
+pub struct Move {
+    pub n: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Starting stacks of crates and the rearrangement procedure.
+#[derive(Debug)]
+pub struct Procedure {
+    pub stacks: Vec<Vec<u8>>,
+    pub moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// Applies all moves with `crane` and returns the crates on top of each
+    /// stack.
+    pub fn rearrange(&self, crane: fn(&mut [Vec<u8>], usize, usize, usize)) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for (i, m) in self.moves.iter().enumerate() {
+            if stacks[m.from].len() < m.n {
+                return Err(anyhow!("not enough crates on stack {} to move {}", m.from + 1, m.n));
+            }
+            crane(&mut stacks, m.from, m.to, m.n);
+            trace!(step = i + 1, n = m.n, from = m.from + 1, to = m.to + 1, "moved crates");
+        }
+        let heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        debug!(moves = self.moves.len(), ?heights, "rearranged");
+
+        stacks
+            .iter()
+            .map(|s| s.last().map(|&c| c as char))
+            .collect::<Option<String>>()
+            .ok_or_else(|| anyhow!("empty stack after rearrangement"))
+    }
+}
+
+/// Checks that the drawing only holds crates marked `[A]` through `[Z]`,
+/// above a line of stack numbers.
+fn check_drawing(drawing: &[(usize, &str)]) -> Result<(), ParseError> {
+    let Some((_numbers, layers)) = drawing.split_last() else {
+        return Err(ParseError::line(1, "", "Missing drawing of the stacks"));
+    };
+
+    for &(n, line) in layers {
+        for (i, c) in line.char_indices() {
+            let valid = match i % 4 {
+                0 => c == '[' || c == ' ',
+                1 => c.is_ascii_uppercase() || c == ' ',
+                2 => c == ']' || c == ' ',
+                _ => c == ' ',
+            };
+            if !valid {
+                let span = &line[i..i + c.len_utf8()];
+                return Err(ParseError::at(n, line, span, format!("Unexpected '{c}' in drawing")));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a line `move N from A to B` of the procedure.
+fn parse_move(n: usize, line: &str, nstacks: usize) -> Result<Move, ParseError> {
+    let stack = |input| match number(input)? {
+        (rest, i @ 1..) if i <= nstacks => Ok((rest, i - 1)),
+        (_, i) => Err(nom::Err::Error(parse::Error::new(input, format!("No stack {i}")))),
+    };
+    let record = tuple((
+        keyed("move", number),
+        spaced(keyed("from", stack)),
+        spaced(keyed("to", stack)),
+    ));
+
+    parse::line(n, line, record.map(|(n, from, to)| Move { n, from, to }))
+}
+
+/// Solution to day 5: Supply Stacks.
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut blocks = blocks(input);
+        let drawing = blocks.next().unwrap_or_default();
+        check_drawing(&drawing)?;
+
+        let layers = drawing
+            .iter()
+            .map(|(_, line)| line.as_bytes())
+            .collect::<Vec<&[u8]>>();
+        let stacks = scan_stacks(&layers);
+
+        let moves = blocks
+            .flatten()
+            .map(|(n, line)| parse_move(n, line, stacks.len()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Self::Input) -> Result<String> { procedure.rearrange(move_crate) }
+
+    fn part2(procedure: &Self::Input) -> Result<String> { procedure.rearrange(move_crates) }
+}
+
+aoc_common::puzzle!(SupplyStacks);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn move_to_missing_stack() {
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 1 to 3\n";
+        let e = SupplyStacks::parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.len), (4, 18, 1));
+    }
+
+    #[test]
+    fn move_onto_same_stack() {
+        let stacks = vec![b"ZN".to_vec(), b"MCD".to_vec(), b"P".to_vec()];
+        for crane in [move_crate, move_crates] {
+            let mut moved = stacks.clone();
+            crane(&mut moved, 1, 1, 2);
+            assert_eq!(moved, stacks);
+        }
+    }
+
+    #[test]
+    fn empty_last_stack() {
+        let input = "[A]\n[B] [C]\n 1   2   3\n\nmove 1 from 1 to 3\n";
+        let procedure = SupplyStacks::parse(input).unwrap();
+        assert_eq!(procedure.stacks.len(), 3);
+        assert_eq!(SupplyStacks::part1(&procedure).unwrap(), "BCA");
+    }
+
+    #[test]
+    fn emptied_stack() {
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 1 to 2\n";
+        let procedure = SupplyStacks::parse(input).unwrap();
+        let e = SupplyStacks::part1(&procedure).unwrap_err();
+        assert_eq!(e.to_string(), "empty stack after rearrangement");
+    }
+
+    #[test]
+    fn not_enough_crates() {
+        let input = "[A] [B]\n 1   2\n\nmove 2 from 1 to 2\n";
+        let procedure = SupplyStacks::parse(input).unwrap();
+        let e = SupplyStacks::part2(&procedure).unwrap_err();
+        assert_eq!(e.to_string(), "not enough crates on stack 1 to move 2");
+    }
+
+    #[test]
+    fn invalid_move() {
+        let input = "[A] [B]\n 1   2\n\nmove 1 from 1\n";
+        let e = SupplyStacks::parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (4, 14, "Unexpected end of line"));
+
+        let input = "[A] [B]\n 1   2\n\nmove 1 form 1 to 2\n";
+        let e = SupplyStacks::parse(input).unwrap_err();
+        assert_eq!((e.line, e.column, e.len, e.message.as_str()), (4, 8, 4, "Expected 'from'"));
+    }
+}
@@ -0,0 +1,7 @@
+// Advent of Code 2022
+// Day 5
+
+use day_05_supply_stacks::PUZZLE;
+use std::process::ExitCode;
+
+fn main() -> ExitCode { aoc_common::run(&PUZZLE) }
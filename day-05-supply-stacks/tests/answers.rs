@@ -0,0 +1,4 @@
+use day_05_supply_stacks::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
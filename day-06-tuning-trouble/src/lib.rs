@@ -0,0 +1,192 @@
+// Advent of Code 2022
+// Day 6
+
+use anyhow::Result;
+use aoc_common::{error::numbered_lines, ParseError, Solution, Streaming};
+use std::io::BufRead;
+
+/// Set of the lowercase letters in `string`, one bit per letter.
+pub fn window_as_bitstring(string: &[u8]) -> u32 {
+    string.iter().fold(0, |acc, v| acc | 1 << (v - b'a') as u32)
+}
+
+/// Number of characters processed before the first window of `window_size`
+/// distinct characters is complete, or 0 if there is none.
+pub fn detect(input: &str, window_size: usize) -> u32 {
+    for (i, w) in input.as_bytes().windows(window_size).enumerate() {
+        if window_as_bitstring(w).count_ones() == window_size as u32 {
+            return (i + window_size) as u32;
+        }
+    }
+
+    0
+ }
+
+/// Solution to day 6: Tuning Trouble.
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = numbered_lines(input.trim_end());
+        let (n, stream) = lines.next().unwrap_or((1, ""));
+        if let Some((n, line)) = lines.next() {
+            return Err(ParseError::line(n, line, "Expected a single line"));
+        }
+        if let Some((i, c)) = stream.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+            let span = &stream[i..i + c.len_utf8()];
+            return Err(ParseError::at(n, stream, span, format!("Invalid character '{c}'")));
+        }
+
+        Ok(stream.to_string())
+    }
+
+    /// Start-of-packet marker
+    fn part1(input: &Self::Input) -> Result<u32> { Ok(detect(input, 4)) }
+
+    /// Start-of-message marker
+    fn part2(input: &Self::Input) -> Result<u32> { Ok(detect(input, 14)) }
+}
+
+impl Streaming for TuningTrouble {
+    /// Tracks where every letter was seen last instead of a window, and from
+    /// that the start of the run of distinct characters ending at the current
+    /// one. A marker is complete once that run is long enough.
+    fn stream(reader: &mut dyn BufRead) -> Result<(u32, u32)> {
+        let mut last_seen = [0u64; 26];
+        let mut run_start = 1u64;
+        let mut markers = [(4u64, 0u64), (14, 0)];
+        let (mut line, mut column, mut count) = (1usize, 0usize, 0u64);
+        let mut ended = false;
+
+        loop {
+            let buf = reader.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            for (i, &c) in buf.iter().enumerate() {
+                if c == b'\n' {
+                    (line, column) = (line + 1, 0);
+                    ended = true;
+                    continue;
+                }
+                column += 1;
+                if c.is_ascii_whitespace() {
+                    ended = true;
+                    continue;
+                }
+                if ended || !c.is_ascii_lowercase() {
+                    let message = match line {
+                        1 => format!("Invalid character '{}'", c.escape_ascii()),
+                        _ => "Expected a single line".to_string(),
+                    };
+                    let excerpt = excerpt(&buf[..=i]);
+                    return Err(ParseError::in_excerpt(line, column, &excerpt, message).into());
+                }
+
+                // Positions count from 1, so that 0 means not seen yet.
+                count += 1;
+                let last = &mut last_seen[usize::from(c - b'a')];
+                run_start = run_start.max(*last + 1);
+                *last = count;
+                for (size, marker) in &mut markers {
+                    if *marker == 0 && count + 1 - run_start >= *size {
+                        *marker = count;
+                    }
+                }
+            }
+            let len = buf.len();
+            reader.consume(len);
+        }
+
+        let [packet, message] = markers.map(|(_, marker)| u32::try_from(marker));
+        Ok((packet?, message?))
+    }
+}
+
+/// End of the line read so far, ending at the last byte of `read`, short
+/// enough to show in an error.
+fn excerpt(read: &[u8]) -> String {
+    let start = read.len().saturating_sub(40);
+    let start = match read[start..].iter().rposition(|&c| c == b'\n') {
+        Some(newline) => start + newline + 1,
+        None => start,
+    };
+    String::from_utf8_lossy(&read[start..]).into_owned()
+}
+
+aoc_common::puzzle!(TuningTrouble, streaming);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bitstring1() {
+        let string = b"aaaa";
+        assert_eq!(window_as_bitstring(string).count_ones(), 1)
+    }
+
+    #[test]
+    fn bitstring2() {
+        let string = b"abaa";
+        assert_eq!(window_as_bitstring(string).count_ones(), 2)
+    }
+
+    #[test]
+    fn bitstring3() {
+        let string = b"abca";
+        assert_eq!(window_as_bitstring(string).count_ones(), 3)
+    }
+
+    #[test]
+    fn bitstring4() {
+        let string = b"abcd";
+        assert_eq!(window_as_bitstring(string).count_ones(), 4)
+    }
+
+    #[test]
+    fn sample1() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(detect(input, 4), 7);
+    }
+
+    #[test]
+    fn sample2() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(detect(input, 4), 5);
+    }
+
+    #[test]
+    fn sample3() {
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(detect(input, 4), 6);
+    }
+
+    #[test]
+    fn sample4() {
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(detect(input, 4), 10);
+    }
+
+    #[test]
+    fn sample5() {
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(detect(input, 4), 11);
+    }
+
+    #[test]
+    fn stream_errors() {
+        let e = TuningTrouble::stream(&mut "abcd1ef\n".as_bytes()).unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 5, "abcd1"));
+
+        let e = TuningTrouble::stream(&mut "abcd\n\n xyz\n".as_bytes()).unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column, e.message.as_str()), (3, 2, "Expected a single line"));
+        assert_eq!(e.text, " x");
+    }
+}
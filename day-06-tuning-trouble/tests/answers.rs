@@ -0,0 +1,4 @@
+use day_06_tuning_trouble::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
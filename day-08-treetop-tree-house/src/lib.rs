@@ -0,0 +1,92 @@
+// Advent of Code 2022
+// Day 8
+
+use aoc_common::{
+    geometry::Direction::{self, *},
+    grid::{Grid, Pos},
+    ParseError, Solution,
+};
+use std::iter;
+use tracing::{debug, trace};
+
+/// Trees visible from outside the grid, found in one sweep from every edge
+/// cell across the grid.
+pub fn visibles(grid: &Grid<u8>) -> Grid<bool> {
+    let mut visible = grid.map(|_| false);
+
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let sweeps = (0..rows)
+        .flat_map(|row| [((row, 0), Right), ((row, cols - 1), Left)])
+        .chain((0..cols).flat_map(|col| [((0, col), Down), ((rows - 1, col), Up)]));
+    for (start, direction) in sweeps {
+        // Trees are only visible if taller than every tree before them.
+        let mut max = None;
+        for (pos, &height) in iter::once((start, &grid[start])).chain(grid.ray(start, direction)) {
+            if Some(height) > max {
+                max = Some(height);
+                visible[pos] = true;
+                trace!(?pos, height, ?direction, "visible");
+            }
+        }
+    }
+    debug!(rows, cols, "swept the forest from every edge");
+
+    visible
+}
+
+/// Viewing distance looking from the tree at `pos` towards `direction`: the
+/// number of trees up to the edge or the first one at least as tall.
+pub fn viewing_distance(grid: &Grid<u8>, pos: Pos, direction: Direction) -> u32 {
+    let mut distance = 0;
+    for (_, &height) in grid.ray(pos, direction) {
+        distance += 1;
+        if height >= grid[pos] {
+            break;
+        }
+    }
+
+    distance
+}
+
+/// Highest scenic score of any tree in the grid.
+pub fn max_scenic_score(grid: &Grid<u8>) -> u32 {
+    let mut best = 0;
+    for pos in grid.positions() {
+        let score = Direction::ALL
+            .iter()
+            .map(|&direction| viewing_distance(grid, pos, direction))
+            .product();
+        trace!(?pos, score, "scenic score");
+        if score > best {
+            best = score;
+            debug!(?pos, score, "new best scenic score");
+        }
+    }
+
+    best
+}
+
+/// Solution to day 8: Treetop Tree House.
+pub struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+    /// Tree heights of the forest.
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |c| match c {
+            '0'..='9' => Ok(c as u8 - b'0'),
+            _ => Err(format!("Invalid height '{c}'")),
+        })
+    }
+
+    fn part1(forest: &Self::Input) -> anyhow::Result<usize> {
+        Ok(visibles(forest).iter().filter(|(_, &visible)| visible).count())
+    }
+
+    fn part2(forest: &Self::Input) -> anyhow::Result<u32> { Ok(max_scenic_score(forest)) }
+}
+
+aoc_common::puzzle!(TreetopTreeHouse);
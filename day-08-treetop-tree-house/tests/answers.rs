@@ -0,0 +1,4 @@
+use day_08_treetop_tree_house::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }
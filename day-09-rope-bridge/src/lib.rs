@@ -0,0 +1,209 @@
+// Advent of Code 2022
+// Day 9
+
+use anyhow::Result;
+use aoc_common::{
+    error::numbered_lines,
+    geometry::{BoundingBox, Direction, Point},
+    parse::{self, from_word, number, spaced},
+    stream::for_each_line,
+    ParseError, Solution, Streaming,
+};
+use nom::sequence::pair;
+use std::{collections::HashSet, fmt, io::BufRead};
+use tracing::{debug, trace};
+
+/// Parses a motion like `R 4`, the input line with number `n`, into its
+/// direction and number of steps.
+pub fn parse_motion(n: usize, line: &str) -> Result<(Direction, usize), ParseError> {
+    parse::line(n, line, pair(from_word(str::parse), spaced(number)))
+}
+
+/// Reads the motions of the head, expanded into single steps.
+pub fn read_motions(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut directions = Vec::new();
+    for (n, line) in numbered_lines(input) {
+        let (direction, steps) = parse_motion(n, line)?;
+        directions.extend((0..steps).map(|_| direction));
+    }
+
+    Ok(directions)
+}
+
+/// A rope moving through the steps of the head.
+#[derive(Debug)]
+pub struct Simulation {
+    /// Knots of the rope, head first.
+    pub rope: Vec<Point>,
+    /// Positions visited by the last knot.
+    pub tail_motions: HashSet<Point>,
+}
+
+impl Simulation {
+    /// Creates a rope of `tail_size` knots behind the head, all at the origin.
+    pub fn new(tail_size: usize) -> Self {
+        let rope = (0..tail_size + 1)
+            .map(|_| Point::ORIGIN)
+            .collect::<Vec<Point>>();
+        let tail_motions = HashSet::new();
+
+        Self { rope, tail_motions }
+    }
+
+    /// Moves the head one step in `d`, dragging the rest of the rope along.
+    pub fn step(&mut self, d: Direction) {
+        // Update head
+        self.rope[0] = self.rope[0].step(d);
+
+        // Update tail
+        for i in 1..self.rope.len() {
+            self.rope[i] = self.rope[i].catch_up(&self.rope[i-1]);
+        }
+
+        let last = self.rope.last().unwrap();
+        self.tail_motions.insert(*last);
+        trace!(direction = ?d, head = %self.rope[0], tail = %last, "step");
+    }
+
+    /// Moves the head through `input`, dragging the rest of the rope along.
+    pub fn run(&mut self, input: &[Direction]) {
+        for d in input {
+            self.step(*d);
+        }
+    }
+}
+
+/// Draws the rope like the puzzle does, the head as `H` and the other knots
+/// by number, on the smallest grid holding the rope and the origin.
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bbox = BoundingBox::of(self.rope.iter().copied().chain([Point::ORIGIN])).unwrap();
+        for y in (bbox.min.y..=bbox.max.y).rev() {
+            for x in bbox.min.x..=bbox.max.x {
+                let p = Point::new(x, y);
+                match self.rope.iter().position(|&knot| knot == p) {
+                    Some(0) => write!(f, "H")?,
+                    Some(i) => write!(f, "{i}")?,
+                    None if p == Point::ORIGIN => write!(f, "s")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Number of positions the tail of a rope with `tail_size` knots behind the
+/// head visits at least once.
+pub fn tail_positions(input: &[Direction], tail_size: usize) -> usize {
+    let mut sim = Simulation::new(tail_size);
+    sim.run(input);
+    debug!(knots = tail_size + 1, visited = sim.tail_motions.len(), "rope at rest:\n{sim}");
+    sim.tail_motions.len()
+}
+
+/// Solution to day 9: Rope Bridge.
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+    type Input = Vec<Direction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> { read_motions(input) }
+
+    fn part1(input: &Self::Input) -> Result<usize> { Ok(tail_positions(input, 1)) }
+
+    fn part2(input: &Self::Input) -> Result<usize> { Ok(tail_positions(input, 9)) }
+}
+
+impl Streaming for RopeBridge {
+    /// Moves both ropes through each motion as it is read, without expanding
+    /// the motions. Only the positions visited by the tails are kept.
+    fn stream(reader: &mut dyn BufRead) -> Result<(usize, usize)> {
+        let mut ropes = [Simulation::new(1), Simulation::new(9)];
+        for_each_line(reader, |n, line| {
+            let (direction, steps) = parse_motion(n, line)?;
+            for _ in 0..steps {
+                ropes.iter_mut().for_each(|rope| rope.step(direction));
+            }
+            Ok(())
+        })?;
+
+        let [short, long] = ropes;
+        Ok((short.tail_motions.len(), long.tail_motions.len()))
+    }
+}
+
+aoc_common::puzzle!(RopeBridge, streaming);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn horizontal() {
+        let (h, t) = (Point::new(2, 4), Point::new(4, 4));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(3, 4));
+    }
+
+    #[test]
+    fn vertical() {
+        let (h, t) = (Point::new(4, 2), Point::new(4, 4));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(4, 3));
+    }
+
+    #[test]
+    fn tail_x_gt_head_x() {
+        // Tail x > Head x
+        let (h, t) = (Point::new(2, 4), Point::new(4, 3));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(3, 4));
+    }
+
+    #[test]
+    fn head_x_gt_tail_x() {
+        // Head x > Tail x
+        let (t, h) = (Point::new(2, 4), Point::new(4, 3));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(3, 3));
+    }
+
+    #[test]
+    fn tail_y_gt_head_y() {
+        let (h, t) = (Point::new(2, 4), Point::new(3, 2));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(2, 3));
+    }
+
+    #[test]
+    fn head_y_gt_tail_y() {
+        let (t, h) = (Point::new(2, 4), Point::new(3, 2));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(3, 3));
+    }
+
+    #[test]
+    fn tail_xy_gt_head_xy() {
+        let (t, h) = (Point::new(2, 4), Point::new(4, 2));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(3, 3));
+    }
+
+    #[test]
+    fn head_xy_gt_tail_xy() {
+        let (t, h) = (Point::new(2, 4), Point::new(4, 2));
+        let cu = t.catch_up(&h);
+        assert_eq!(cu, Point::new(3, 3));
+    }
+
+    #[test]
+    fn draw_rope() {
+        let mut sim = Simulation::new(2);
+        sim.run(&[Direction::Right, Direction::Right, Direction::Up, Direction::Up]);
+        assert_eq!(sim.to_string(), "..H\n.21\ns..\n");
+    }
+}
@@ -0,0 +1,4 @@
+use day_09_rope_bridge::PUZZLE;
+
+#[test]
+fn expected_answers() -> anyhow::Result<()> { aoc_common::expected::check(&PUZZLE) }